@@ -10,7 +10,10 @@ use std::sync::Arc;
 use harvest_moon_runtime::opaque::Block;
 use harvest_moon_runtime::AccountId;
 use harvest_moon_runtime::Balance;
+use harvest_moon_runtime::BlockNumber;
+use harvest_moon_runtime::Hash;
 use harvest_moon_runtime::Nonce;
+use harvest_moon_runtime::VoteCredit;
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
@@ -37,11 +40,21 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_voting_rpc::VotingRuntimeApi<
+        Block,
+        AccountId,
+        Balance,
+        BlockNumber,
+        Hash,
+        VoteCredit,
+    >,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
     use pallet_transaction_payment_rpc::TransactionPayment;
     use pallet_transaction_payment_rpc::TransactionPaymentApiServer;
+    use pallet_voting_rpc::Voting;
+    use pallet_voting_rpc::VotingApiServer;
     use substrate_frame_rpc_system::System;
     use substrate_frame_rpc_system::SystemApiServer;
 
@@ -49,7 +62,8 @@ where
     let FullDeps { client, pool } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(Voting::new(client).into_rpc())?;
 
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
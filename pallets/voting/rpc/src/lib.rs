@@ -0,0 +1,228 @@
+//! RPC interface for the voting pallet.
+//!
+//! Every method takes an optional block hash and queries the best block when
+//! it is omitted.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use jsonrpsee::types::error::ErrorObject;
+use jsonrpsee::types::ErrorObjectOwned;
+use pallet_voting_runtime_api::Candidate;
+use pallet_voting_runtime_api::NoteTarget;
+use pallet_voting_runtime_api::PayoutEstimate;
+use pallet_voting_runtime_api::ProposalInfo;
+use pallet_voting_runtime_api::ProposalResult;
+use pallet_voting_runtime_api::Reputation;
+use pallet_voting_runtime_api::VoterBalance;
+pub use pallet_voting_runtime_api::VotingApi as VotingRuntimeApi;
+use sp_api::ApiError;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait VotingApi<BlockHash, AccountId, Balance, BlockNumber, Hash, VoteCredit> {
+    #[method(name = "voting_activeProposals")]
+    fn active_proposals(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalInfo<AccountId, BlockNumber, Hash>>>;
+
+    #[method(name = "voting_proposal")]
+    fn proposal(
+        &self,
+        proposal_hash: Hash,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalInfo<AccountId, BlockNumber, Hash>>>;
+
+    #[method(name = "voting_proposalResult")]
+    fn proposal_result(
+        &self,
+        proposal_hash: Hash,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalResult<BlockNumber, Balance>>>;
+
+    #[method(name = "voting_member")]
+    fn member(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<VoterBalance<Balance, VoteCredit>>>;
+
+    #[method(name = "voting_pendingCommits")]
+    fn pending_commits(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Hash, VoteCredit)>>;
+
+    #[method(name = "voting_estimatePayout")]
+    fn estimate_payout(
+        &self,
+        account: AccountId,
+        proposal_hash: Hash,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<PayoutEstimate<Balance>>>;
+
+    #[method(name = "voting_reputation")]
+    fn reputation(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Reputation<Balance>>;
+
+    #[method(name = "voting_notesForTarget")]
+    fn notes_for_target(
+        &self,
+        target: NoteTarget<Hash>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Hash>>;
+
+    #[method(name = "voting_candidates")]
+    fn candidates(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(Hash, Candidate<Hash, BlockNumber>)>>;
+}
+
+/// Provides RPC methods to query proposals and members of the voting pallet.
+pub struct Voting<C, Block> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Voting<C, Block> {
+    /// Creates a new instance of the Voting Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+fn map_err(error: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query the voting pallet.",
+        Some(error.to_string()),
+    )
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber, Hash, VoteCredit>
+    VotingApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber, Hash, VoteCredit>
+    for Voting<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: VotingRuntimeApi<Block, AccountId, Balance, BlockNumber, Hash, VoteCredit>,
+    AccountId: Codec + Send + Sync + 'static,
+    Balance: Codec + Send + Sync + 'static,
+    BlockNumber: Codec + Send + Sync + 'static,
+    Hash: Codec + Send + Sync + 'static,
+    VoteCredit: Codec + Send + Sync + 'static,
+{
+    fn active_proposals(
+        &self,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ProposalInfo<AccountId, BlockNumber, Hash>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().active_proposals(at_hash).map_err(map_err)
+    }
+
+    fn proposal(
+        &self,
+        proposal_hash: Hash,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ProposalInfo<AccountId, BlockNumber, Hash>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().proposal(at_hash, proposal_hash).map_err(map_err)
+    }
+
+    fn proposal_result(
+        &self,
+        proposal_hash: Hash,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<ProposalResult<BlockNumber, Balance>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .proposal_result(at_hash, proposal_hash)
+            .map_err(map_err)
+    }
+
+    fn member(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<VoterBalance<Balance, VoteCredit>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().member(at_hash, account).map_err(map_err)
+    }
+
+    fn pending_commits(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(Hash, VoteCredit)>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().pending_commits(at_hash, account).map_err(map_err)
+    }
+
+    fn estimate_payout(
+        &self,
+        account: AccountId,
+        proposal_hash: Hash,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<PayoutEstimate<Balance>>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .estimate_payout(at_hash, account, proposal_hash)
+            .map_err(map_err)
+    }
+
+    fn reputation(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Reputation<Balance>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().reputation(at_hash, account).map_err(map_err)
+    }
+
+    fn notes_for_target(
+        &self,
+        target: NoteTarget<Hash>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<Hash>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().notes_for_target(at_hash, target).map_err(map_err)
+    }
+
+    fn candidates(
+        &self,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(Hash, Candidate<Hash, BlockNumber>)>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client.runtime_api().candidates(at_hash).map_err(map_err)
+    }
+}
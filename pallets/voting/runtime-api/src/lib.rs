@@ -0,0 +1,54 @@
+//! Runtime API definition for the voting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use codec::Codec;
+pub use pallet_voting::types::Candidate;
+pub use pallet_voting::types::NoteTarget;
+pub use pallet_voting::types::PayoutEstimate;
+pub use pallet_voting::types::ProposalInfo;
+pub use pallet_voting::types::ProposalResult;
+pub use pallet_voting::types::Reputation;
+pub use pallet_voting::types::VoterBalance;
+
+sp_api::decl_runtime_apis! {
+    pub trait VotingApi<AccountId, Balance, BlockNumber, Hash, VoteCredit>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+        VoteCredit: Codec,
+    {
+        /// Active proposals with the phase they are in.
+        fn active_proposals() -> Vec<ProposalInfo<AccountId, BlockNumber, Hash>>;
+
+        /// An active proposal, its tally is disclosed once the reveal phase started.
+        fn proposal(proposal_hash: Hash) -> Option<ProposalInfo<AccountId, BlockNumber, Hash>>;
+
+        /// The archived outcome of a closed proposal.
+        fn proposal_result(proposal_hash: Hash) -> Option<ProposalResult<BlockNumber, Balance>>;
+
+        /// Voting tokens and reserved funds of a member.
+        fn member(account: AccountId) -> Option<VoterBalance<Balance, VoteCredit>>;
+
+        /// Proposals the account committed on and did not reveal yet.
+        fn pending_commits(account: AccountId) -> Vec<(Hash, VoteCredit)>;
+
+        /// What the account would be rewarded and slashed if the proposal was closed now.
+        fn estimate_payout(account: AccountId, proposal_hash: Hash) -> Option<PayoutEstimate<Balance>>;
+
+        /// The track record of a current or former member.
+        fn reputation(account: AccountId) -> Reputation<Balance>;
+
+        /// Active and archived proposals annotating the content of the target.
+        fn notes_for_target(target: NoteTarget<Hash>) -> Vec<Hash>;
+
+        /// Posts imported from the feed that wait for a note, keyed by the hash of their target.
+        fn candidates() -> Vec<(Hash, Candidate<Hash, BlockNumber>)>;
+    }
+}
@@ -0,0 +1,770 @@
+//! Benchmarking setup for pallet-voting
+
+use codec::Decode;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
+use frame_support::traits::ReservableCurrency;
+use frame_system::offchain::SigningTypes;
+use frame_system::RawOrigin;
+use scale_info::prelude::boxed::Box;
+use scale_info::prelude::vec;
+use scale_info::prelude::vec::Vec;
+use sp_runtime::traits::Hash;
+use sp_runtime::traits::IdentifyAccount;
+use sp_runtime::traits::TrailingZeroInput;
+
+use super::*;
+use crate::types::Data;
+use crate::types::OptionIndex;
+use crate::types::RatingLabel;
+use crate::types::RatingOptions;
+use crate::types::MAX_OPTIONS;
+#[allow(unused)]
+use crate::Pallet as VotingModule;
+
+const SEED: u32 = 0;
+const SALT: types::Salt = [7u8; 32];
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+    frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+/// An account with a judged identity and enough funds to join the committee.
+/// The identity is forged through `IdentityProvider`, which only offers it
+/// with the `runtime-benchmarks` feature.
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who: T::AccountId = account(name, index, SEED);
+    let funds = T::BasicDeposit::get().saturating_mul(10u32.into());
+    T::Currency::make_free_balance_be(&who, funds.saturating_add(T::Currency::minimum_balance()));
+    T::IdentityProvider::ensure_identity(&who, name.as_bytes())
+        .expect("benchmark account can set an identity");
+    T::IdentityProvider::ensure_judgement(&account("registrar", 0, SEED), &who)
+        .expect("benchmark identity can be judged");
+    who
+}
+
+fn member<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let who = funded::<T>(name, index);
+    VotingModule::<T>::join_committee(RawOrigin::Signed(who.clone()).into())
+        .expect("member can join the committee");
+    who
+}
+
+fn note(i: u32) -> Box<Data> { Box::new(Data::Raw(i.encode().try_into().unwrap())) }
+
+/// The largest set of rating options a proposal can carry.
+fn max_options() -> RatingOptions {
+    let label = RatingLabel::truncate_from(vec![b'o'; 64]);
+    RatingOptions::truncate_from(vec![label; MAX_OPTIONS as usize])
+}
+
+/// A target with the longest platform identifier.
+fn target<T: Config>() -> NoteTargetOf<T> {
+    types::NoteTarget {
+        platform: types::Platform::truncate_from(vec![b'p'; 32]),
+        content: T::Hashing::hash_of(&b"https://example.com/post"),
+        timestamp: Some(0),
+    }
+}
+
+fn propose<T: Config>(proposer: &T::AccountId, i: u32) -> T::Hash {
+    let note = note(i);
+    let proposal_hash = T::Hashing::hash_of(&note);
+    VotingModule::<T>::create_proposal(
+        RawOrigin::Signed(proposer.clone()).into(),
+        note,
+        target::<T>(),
+        T::MinLength::get(),
+        None,
+        None,
+    )
+    .expect("member can create a proposal");
+    proposal_hash
+}
+
+fn commit<T: Config>(who: &T::AccountId, proposal: T::Hash, option: OptionIndex) {
+    let commitment = VotingModule::<T>::commitment_of(&option, &SALT, who, &proposal);
+    VotingModule::<T>::commit_vote(
+        RawOrigin::Signed(who.clone()).into(),
+        proposal,
+        commitment,
+        1u32.into(),
+    )
+    .expect("member can commit");
+}
+
+fn reveal<T: Config>(who: &T::AccountId, proposal: T::Hash, option: OptionIndex) {
+    VotingModule::<T>::reveal_vote(
+        RawOrigin::Signed(who.clone()).into(),
+        proposal,
+        option,
+        SALT,
+    )
+    .expect("commit can be revealed");
+}
+
+/// Has `delegator` delegate its credits on every note to `delegate`.
+fn delegate_to<T: Config>(delegator: &T::AccountId, delegate: &T::AccountId) {
+    VotingModule::<T>::delegate(
+        RawOrigin::Signed(delegator.clone()).into(),
+        delegate.clone(),
+        None,
+    )
+    .expect("member can delegate");
+}
+
+/// Has `who` delegate `n` topics, each to a member of its own.
+fn delegate_topics<T: Config>(who: &T::AccountId, n: u32) {
+    for i in 0..n {
+        let topic = (i > 0).then(|| types::Platform::truncate_from(vec![b'p'; i as usize]));
+        VotingModule::<T>::delegate(
+            RawOrigin::Signed(who.clone()).into(),
+            member::<T>("delegate", i),
+            topic,
+        )
+        .expect("member can delegate");
+    }
+}
+
+/// Moves the chain to the end of the commit phase and starts the reveal phase.
+fn start_reveal<T: Config>(proposal: T::Hash) {
+    let poll_end = ProposalData::<T>::get(proposal).unwrap().poll_end;
+    frame_system::Pallet::<T>::set_block_number(poll_end);
+    VotingModule::<T>::do_close_vote(proposal).unwrap();
+}
+
+/// Moves the chain to the end of the reveal phase.
+fn end_reveal<T: Config>(proposal: T::Hash) {
+    let reveal_end = ProposalData::<T>::get(proposal).unwrap().reveal_end.unwrap();
+    frame_system::Pallet::<T>::set_block_number(reveal_end);
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn join_committee() {
+        let caller: T::AccountId = funded::<T>("caller", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(Event::Joined(caller).into());
+    }
+
+    // `d` delegations are removed: the leaving member delegates up to
+    // `MAX_DELEGATIONS` topics and the other members delegate to it
+    #[benchmark]
+    fn leave_committee(d: Linear<0, { T::MaxDelegators::get() + types::MAX_DELEGATIONS }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let delegations = d.min(types::MAX_DELEGATIONS);
+        delegate_topics::<T>(&caller, delegations);
+        for i in 0..d - delegations {
+            delegate_to::<T>(&member::<T>("delegator", i), &caller);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(!VotingModule::<T>::is_member(&caller));
+        assert!(Delegations::<T>::get(&caller).is_empty());
+        assert!(Delegators::<T>::get(&caller).is_empty());
+    }
+
+    // `p` active proposals once the new one is created
+    #[benchmark]
+    fn create_proposal(p: Linear<1, { T::MaxProposals::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        for i in 1..p {
+            propose::<T>(&caller, i);
+        }
+        let call: <T as Config>::RuntimeCall =
+            frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+        let proposal_hash = T::Hashing::hash_of(&note(p));
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            note(p),
+            target::<T>(),
+            T::MinLength::get(),
+            Some(max_options()),
+            Some(Box::new(call)),
+        );
+
+        assert_last_event::<T>(
+            Event::Targeted {
+                proposal_hash,
+                target: target::<T>(),
+            }
+            .into(),
+        );
+        assert!(ProposalCalls::<T>::contains_key(proposal_hash));
+    }
+
+    #[benchmark]
+    fn close_vote() {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        let poll_end = ProposalData::<T>::get(proposal).unwrap().poll_end;
+        frame_system::Pallet::<T>::set_block_number(poll_end);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal);
+
+        assert_last_event::<T>(Event::ClosedCommit(proposal).into());
+    }
+
+    #[benchmark]
+    fn commit_vote() {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        let commitment = VotingModule::<T>::commitment_of(&0, &SALT, &caller, &proposal);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller.clone()),
+            proposal,
+            commitment,
+            2u32.into(),
+        );
+
+        assert!(Commits::<T>::contains_key(&caller, proposal));
+    }
+
+    // `v` voters revealed before the benchmarked one
+    #[benchmark]
+    fn reveal_vote(v: Linear<0, { T::MaxVotersPerProposal::get() - 1 }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        let voters: Vec<T::AccountId> = (0..v).map(|i| member::<T>("voter", i)).collect();
+        for voter in voters.iter() {
+            commit::<T>(voter, proposal, 0);
+        }
+        commit::<T>(&caller, proposal, 0);
+        start_reveal::<T>(proposal);
+        for voter in voters.iter() {
+            reveal::<T>(voter, proposal, 0);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal, 0, SALT);
+
+        assert_eq!(ProposalData::<T>::get(proposal).unwrap().revealed, v + 1);
+    }
+
+    // `d` members delegate to the revealing one, each given votes on its behalf
+    #[benchmark]
+    fn reveal_delegated(d: Linear<0, { T::MaxDelegators::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        for i in 0..d {
+            delegate_to::<T>(&member::<T>("delegator", i), &caller);
+        }
+        commit::<T>(&caller, proposal, 0);
+        start_reveal::<T>(proposal);
+
+        #[extrinsic_call]
+        reveal_vote(RawOrigin::Signed(caller), proposal, 0, SALT);
+
+        assert_eq!(ProposalData::<T>::get(proposal).unwrap().revealed, d + 1);
+    }
+
+    // `v` revealed votes, the majority on the first option so that both sides
+    // are paid out
+    #[benchmark]
+    fn close_reveal(v: Linear<1, { T::MaxVotersPerProposal::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        let voters: Vec<(T::AccountId, OptionIndex)> = (0..v)
+            .map(|i| (member::<T>("voter", i), if i <= v / 2 { 0 } else { 1 }))
+            .collect();
+        for (voter, option) in voters.iter() {
+            commit::<T>(voter, proposal, *option);
+        }
+        start_reveal::<T>(proposal);
+        for (voter, option) in voters.iter() {
+            reveal::<T>(voter, proposal, *option);
+        }
+        end_reveal::<T>(proposal);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal);
+
+        assert!(ProposalResults::<T>::contains_key(proposal));
+    }
+
+    #[benchmark]
+    fn prune_result() {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        commit::<T>(&caller, proposal, 0);
+        start_reveal::<T>(proposal);
+        reveal::<T>(&caller, proposal, 0);
+        end_reveal::<T>(proposal);
+        VotingModule::<T>::do_close_reveal(proposal).unwrap();
+        let closed_at = ProposalResults::<T>::get(proposal).unwrap().closed_at;
+        frame_system::Pallet::<T>::set_block_number(closed_at + T::ResultRetention::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal);
+
+        assert_last_event::<T>(Event::ResultPruned(proposal).into());
+    }
+
+    // closing the proposal reaps `ReapLimit` commits, `n` are left to reap
+    #[benchmark]
+    fn reap_unrevealed(n: Linear<1, { T::ReapLimit::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        for i in 0..n + T::ReapLimit::get() {
+            commit::<T>(&member::<T>("voter", i), proposal, 0);
+        }
+        start_reveal::<T>(proposal);
+        end_reveal::<T>(proposal);
+        VotingModule::<T>::do_close_reveal(proposal).unwrap();
+        assert_eq!(Committers::<T>::iter_prefix(proposal).count() as u32, n);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal, n);
+
+        assert_eq!(Committers::<T>::iter_prefix(proposal).count(), 0);
+    }
+
+    // funds held in the pot are shared between `b` members
+    #[benchmark]
+    fn spend_pot(b: Linear<1, { T::MaxBeneficiaries::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::PotOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let beneficiaries: Vec<T::AccountId> = (0..b).map(|i| member::<T>("voter", i)).collect();
+        let pot = VotingModule::<T>::account_id();
+        let amount = T::BasicDeposit::get().saturating_mul(b.into());
+        T::Currency::make_free_balance_be(
+            &pot,
+            amount.saturating_add(T::Currency::minimum_balance()),
+        );
+        T::Currency::reserve(&pot, amount)?;
+        Pot::<T>::put(types::PotLedger {
+            inflow: amount,
+            unclaimed: amount,
+            ..Default::default()
+        });
+        let spend = types::PotSpend::Redistribute(beneficiaries.try_into().unwrap());
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            amount,
+            spend,
+        );
+
+        assert_eq!(Pot::<T>::get().distributed, amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn refresh_judgement() {
+        let caller: T::AccountId = funded::<T>("caller", 0);
+        let member: T::AccountId = member::<T>("member", 0);
+        Suspended::<T>::insert(&member, frame_system::Pallet::<T>::block_number());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), member.clone());
+
+        assert_last_event::<T>(Event::MemberReinstated { account: member }.into());
+    }
+
+    // every open proposal is checked for a vote of the member
+    #[benchmark]
+    fn declare_viewpoint(p: Linear<1, { T::MaxProposals::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposer: T::AccountId = member::<T>("proposer", 0);
+        for i in 0..p {
+            propose::<T>(&proposer, i);
+        }
+        // a viewpoint declared before, whose cooldown just ran out
+        ViewpointChanges::<T>::insert(&caller, BlockNumberFor::<T>::zero());
+        frame_system::Pallet::<T>::set_block_number(T::ViewpointCooldown::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), 0);
+
+        assert_eq!(Viewpoints::<T>::get(&caller), Some(0));
+    }
+
+    #[benchmark]
+    fn set_viewpoint(p: Linear<1, { T::MaxProposals::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::ViewpointOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let proposer: T::AccountId = member::<T>("proposer", 0);
+        let member: T::AccountId = member::<T>("member", 0);
+        for i in 0..p {
+            propose::<T>(&proposer, i);
+        }
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            member.clone(),
+            Some(0),
+        );
+
+        assert_eq!(Viewpoints::<T>::get(&member), Some(0));
+        Ok(())
+    }
+
+    // every candidate is new, so each one is checked and stored
+    #[benchmark]
+    fn submit_candidates(n: Linear<1, { T::MaxImportBatch::get() }>) {
+        let candidates: Vec<NoteTargetOf<T>> = (0..n)
+            .map(|i| types::NoteTarget {
+                platform: types::Platform::truncate_from(vec![b'p'; 32]),
+                content: T::Hashing::hash_of(&i),
+                timestamp: Some(0),
+            })
+            .collect();
+        // the signature is checked when validating the transaction only
+        let public = <T as SigningTypes>::Public::decode(&mut TrailingZeroInput::zeroes())
+            .expect("infinite input");
+        let signature = <T as SigningTypes>::Signature::decode(&mut TrailingZeroInput::zeroes())
+            .expect("infinite input");
+        Importers::<T>::insert(public.clone().into_account(), ());
+        let payload = ImportPayload {
+            candidates: ImportBatchOf::<T>::truncate_from(candidates),
+            import_at: NextImportAt::<T>::get(),
+            public,
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::None, payload, signature);
+
+        assert_eq!(Candidates::<T>::count(), n.min(T::MaxCandidates::get()));
+    }
+
+    #[benchmark]
+    fn expire_candidate() {
+        let caller: T::AccountId = account("caller", 0, 0);
+        let target = target::<T>();
+        let key = VotingModule::<T>::target_key(&target);
+        Candidates::<T>::insert(
+            key,
+            types::Candidate {
+                target,
+                imported_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::CandidateLifetime::get(),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), key);
+
+        assert_last_event::<T>(Event::CandidateExpired { key }.into());
+    }
+
+    #[benchmark]
+    fn set_importer() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let importer: T::AccountId = account("importer", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            importer.clone(),
+            true,
+        );
+
+        assert!(Importers::<T>::contains_key(&importer));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn dismiss_candidate() {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let target = target::<T>();
+        let key = VotingModule::<T>::target_key(&target);
+        Candidates::<T>::insert(
+            key,
+            types::Candidate {
+                target,
+                imported_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), key);
+
+        assert_last_event::<T>(
+            Event::CandidateDismissed {
+                key,
+                account: caller,
+            }
+            .into(),
+        );
+    }
+
+    // the chain above the delegate is walked `c` members deep
+    #[benchmark]
+    fn delegate(c: Linear<1, { T::MaxDelegationDepth::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let chain: Vec<T::AccountId> = (0..c).map(|i| member::<T>("delegate", i)).collect();
+        for pair in chain.windows(2) {
+            delegate_to::<T>(&pair[0], &pair[1]);
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), chain[0].clone(), None);
+
+        assert_last_event::<T>(
+            Event::Delegated {
+                delegator: caller,
+                delegate: chain[0].clone(),
+                topic: None,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn undelegate() {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        delegate_to::<T>(&caller, &member::<T>("delegate", 0));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), None);
+
+        assert!(Delegations::<T>::get(&caller).is_empty());
+    }
+
+    // credits are only replenished when they regenerate in epochs, with
+    // another policy the call fails and that is what is measured
+    #[benchmark]
+    fn replenish_credits() {
+        let caller: T::AccountId = funded::<T>("caller", 0);
+        let member: T::AccountId = member::<T>("member", 0);
+        let epochs = match T::CreditPolicy::get() {
+            types::CreditPolicy::Epochs { length, .. } => {
+                frame_system::Pallet::<T>::set_block_number(length);
+                true
+            }
+            _ => false,
+        };
+
+        #[block]
+        {
+            let replenished = VotingModule::<T>::replenish_credits(
+                RawOrigin::Signed(caller).into(),
+                member.clone(),
+            );
+            assert_eq!(replenished.is_ok(), epochs);
+        }
+
+        assert_eq!(CreditEpochs::<T>::get(&member), epochs as u32);
+    }
+
+    // the proposal is the last one of the active set
+    #[benchmark]
+    fn withdraw_proposal(p: Linear<1, { T::MaxProposals::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposals: Vec<T::Hash> = (0..p).map(|i| propose::<T>(&caller, i)).collect();
+        let proposal = proposals[p as usize - 1];
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), proposal);
+
+        assert_last_event::<T>(
+            Event::Withdrawn {
+                proposal_hash: proposal,
+                proposer: caller,
+            }
+            .into(),
+        );
+    }
+
+    // every voter committed and is refunded
+    #[benchmark]
+    fn cancel_proposal(v: Linear<0, { T::ReapLimit::get() }>) -> Result<(), BenchmarkError> {
+        let origin =
+            T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        for i in 0..v {
+            commit::<T>(&member::<T>("voter", i), proposal, 0);
+        }
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            proposal,
+        );
+
+        assert_last_event::<T>(
+            Event::Cancelled {
+                proposal_hash: proposal,
+                refunded: v,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn refund_cancelled(v: Linear<1, { T::ReapLimit::get() }>) {
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        for i in 0..v {
+            commit::<T>(&member::<T>("voter", i), proposal, 0);
+        }
+        let proposal_data = ProposalData::<T>::get(proposal).unwrap();
+        VotingModule::<T>::remove_proposal(proposal, &proposal_data);
+        CancelledProposals::<T>::insert(proposal, ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), proposal, v);
+
+        assert!(!CancelledProposals::<T>::contains_key(proposal));
+    }
+
+    // the member revealed a vote on `p` proposals and `d` delegations are
+    // removed as in `leave_committee`
+    #[benchmark]
+    fn force_remove_member(
+        p: Linear<0, { T::MaxProposals::get() }>,
+        d: Linear<0, { T::MaxDelegators::get() + types::MAX_DELEGATIONS }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let removed: T::AccountId = member::<T>("removed", 0);
+        let proposals: Vec<T::Hash> = (0..p).map(|i| propose::<T>(&caller, i)).collect();
+        for proposal in proposals.iter() {
+            commit::<T>(&removed, *proposal, 0);
+        }
+        for proposal in proposals.iter() {
+            start_reveal::<T>(*proposal);
+            reveal::<T>(&removed, *proposal, 0);
+        }
+        let delegations = d.min(types::MAX_DELEGATIONS);
+        delegate_topics::<T>(&removed, delegations);
+        for i in 0..d - delegations {
+            delegate_to::<T>(&member::<T>("delegator", i), &removed);
+        }
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            removed.clone(),
+        );
+
+        assert!(!VotingModule::<T>::is_member(&removed));
+        assert!(Delegators::<T>::get(&removed).is_empty());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_close_vote() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            proposal,
+        );
+
+        assert_last_event::<T>(
+            Event::ForceClosed {
+                proposal_hash: proposal,
+                phase: types::Phase::Commit,
+            }
+            .into(),
+        );
+        Ok(())
+    }
+
+    // `v` revealed votes, as for `close_reveal`
+    #[benchmark]
+    fn force_close_reveal(
+        v: Linear<1, { T::MaxVotersPerProposal::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = member::<T>("caller", 0);
+        let proposal = propose::<T>(&caller, 0);
+        let voters: Vec<(T::AccountId, OptionIndex)> = (0..v)
+            .map(|i| (member::<T>("voter", i), if i <= v / 2 { 0 } else { 1 }))
+            .collect();
+        for (voter, option) in voters.iter() {
+            commit::<T>(voter, proposal, *option);
+        }
+        start_reveal::<T>(proposal);
+        for (voter, option) in voters.iter() {
+            reveal::<T>(voter, proposal, *option);
+        }
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            proposal,
+        );
+
+        assert!(ProposalResults::<T>::contains_key(proposal));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn force_set_member_balance() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let caller: T::AccountId = member::<T>("caller", 0);
+        // the funds given back are checked against every bond of the member
+        for i in 0..T::MaxProposals::get() {
+            propose::<T>(&caller, i);
+        }
+        let bonds = VotingModule::<T>::bonds_of(&caller);
+        let balance = types::VoterBalance {
+            voting_tokens: T::MaxVotingTokens::get(),
+            reserved_balance: Members::<T>::get(&caller)
+                .reserved_balance
+                .saturating_sub(T::Currency::minimum_balance()),
+        };
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            caller.clone(),
+            balance.clone(),
+        );
+
+        assert_eq!(Members::<T>::get(&caller), balance);
+        assert_eq!(
+            T::Currency::reserved_balance(&caller),
+            balance.reserved_balance.saturating_add(bonds)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_parameter() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ParameterOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let parameter = types::VotingParameter::MaxProposals(Some(T::MaxProposals::get()));
+
+        #[extrinsic_call]
+        _(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            parameter,
+        );
+
+        assert_last_event::<T>(Event::ParameterSet { parameter }.into());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(VotingModule, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -16,30 +16,72 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 // We need to define the types used in this pallet.
 pub mod types;
 
+pub mod migrations;
+
+pub mod offchain;
+
+pub mod outcome;
+
+pub mod slashing;
+
 pub mod weights;
-use codec::alloc::borrow::ToOwned;
+use core::marker::PhantomData;
+
+use codec::Decode;
+use frame_support::dispatch::DispatchResult;
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::ensure;
+use frame_support::storage::with_storage_layer;
+use frame_support::traits::EnsureOrigin;
+use frame_support::traits::OriginTrait;
 use frame_support::traits::ReservableCurrency;
+use frame_support::weights::Weight;
 use frame_support::BoundedVec;
+use frame_system::offchain::SendUnsignedTransaction;
+use frame_system::offchain::Signer;
+use offchain::ImportError;
+use offchain::ImportPayload;
+use outcome::OutcomeRule;
 use scale_info::prelude::vec;
 use scale_info::prelude::vec::Vec;
+use slashing::SlashPolicy;
 use sp_runtime::traits::AccountIdConversion;
-use sp_runtime::traits::CheckedDiv;
+use sp_runtime::traits::CheckedMul;
+use sp_runtime::traits::Dispatchable;
 use sp_runtime::traits::Get;
+use sp_runtime::traits::Hash;
+use sp_runtime::traits::IntegerSquareRoot;
+use sp_runtime::traits::Zero;
 use sp_runtime::DispatchError;
+use sp_runtime::Perbill;
+use sp_runtime::SaturatedConversion;
 use sp_runtime::Saturating;
+use types::CreditPolicy;
+use types::Epoch;
+use types::Outcome;
+use types::PayoutEstimate;
+use types::Phase;
+use types::ProposalInfo;
+use types::TokenPolicy;
+use types::Topic;
+use types::Viewpoint;
 pub use weights::*;
 
 // All pallet logic is defined in its own module and must be annotated by the
 // `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
-    use core::cmp::Ordering;
-
     // Import various useful types required by all FRAME pallets.
     use frame_support::dispatch::DispatchResult;
+    use frame_support::dispatch::DispatchResultWithPostInfo;
+    use frame_support::dispatch::GetDispatchInfo;
+    use frame_support::dispatch::PostDispatchInfo;
     use frame_support::pallet_prelude::CountedStorageMap;
     use frame_support::pallet_prelude::ValueQuery;
     use frame_support::pallet_prelude::*;
@@ -47,17 +89,40 @@ pub mod pallet {
     use frame_support::traits::Currency;
     use frame_support::traits::ReservableCurrency;
     use frame_support::PalletId;
+    use frame_system::offchain::AppCrypto;
+    use frame_system::offchain::SendTransactionTypes;
+    use frame_system::offchain::SignedPayload;
+    use frame_system::offchain::SigningTypes;
     use frame_system::pallet_prelude::*;
     use scale_info::prelude::boxed::Box;
-    use scale_info::prelude::vec::Vec;
+    use sp_runtime::traits::AtLeast32BitUnsigned;
+    use sp_runtime::traits::Dispatchable;
     use sp_runtime::traits::IdentifyAccount;
-    use sp_runtime::traits::Verify;
+    use types::Breakdown;
+    use types::Candidate;
     use types::Commit;
     use types::Data;
+    use types::Delegates;
+    use types::IdentityJudgement;
+    use types::NoteTarget;
+    use types::OptionIndex;
+    use types::Phase;
+    use types::PotLedger;
+    use types::PotSpend;
     use types::Proposal;
-    use types::Vote;
-    use types::VoteToken;
+    use types::ProposalResult;
+    use types::RatingOptions;
+    use types::Reputation;
+    use types::Salt;
+    use types::Tally;
+    use types::TokenPolicy;
+    use types::Topic;
+    use types::Viewpoint;
+    use types::VoteRecord;
     use types::VoterBalance;
+    use types::VotingParameter;
+    use types::VotingParameters;
+    use types::Winners;
 
     use super::*;
 
@@ -65,15 +130,94 @@ pub mod pallet {
     pub type ProposalIndex = u32;
     pub type BlockNumber = u32;
 
+    /// The most phases that can end in a single block: the commit or reveal
+    /// phase of every active proposal, and the retention period of as many
+    /// closed ones.
+    pub struct MaxDeadlines<T>(PhantomData<T>);
+
+    impl<T: Config> Get<u32> for MaxDeadlines<T> {
+        fn get() -> u32 { T::MaxProposals::get().saturating_mul(2) }
+    }
+
+    /// Log target of the offchain worker.
+    pub const LOG_TARGET: &str = "runtime::voting";
+
+    /// The in-code storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+    pub type CommitOf<T> = Commit<<T as frame_system::Config>::Hash, <T as Config>::VoteCredit>;
+    pub type VoteRecordOf<T> = VoteRecord<<T as Config>::VoteCredit>;
+    pub type VoterBalanceOf<T> = VoterBalance<BalanceOf<T>, <T as Config>::VoteCredit>;
+    pub type VotingParameterOf<T> =
+        VotingParameter<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::VoteCredit>;
+    pub type VotingParametersOf<T> =
+        VotingParameters<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::VoteCredit>;
+    pub type ReputationOf<T> = Reputation<BalanceOf<T>>;
+    pub type NoteTargetOf<T> = NoteTarget<<T as frame_system::Config>::Hash>;
+    pub type CandidateOf<T> = Candidate<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+    /// Members delegating to a member, with the topic they delegate
+    pub type DelegatorsOf<T> =
+        BoundedVec<(<T as frame_system::Config>::AccountId, Topic), <T as Config>::MaxDelegators>;
+    /// Posts submitted by the offchain worker in a single import
+    pub type ImportBatchOf<T> = BoundedVec<NoteTargetOf<T>, <T as Config>::MaxImportBatch>;
+    /// An import signed by an importer
+    pub type ImportPayloadOf<T> =
+        ImportPayload<<T as SigningTypes>::Public, BlockNumberFor<T>, ImportBatchOf<T>>;
+    /// Platform and content hash of the target of a genesis proposal
+    pub type GenesisTargetOf<T> = (Vec<u8>, <T as frame_system::Config>::Hash);
+    pub type PotSpendOf<T> = PotSpend<
+        BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxBeneficiaries>,
+    >;
+
     // type ProposalOf<T> =
     //     Box<Proposal<<T as frame_system::Config>::AccountId, <T as
     // frame_system::Config>::Block>>;
 
     pub trait IdentityProvider<AccountId> {
         fn check_existence(account: &AccountId) -> bool;
+
+        /// The best judgement given to the identity of `account`, or `None`
+        /// if it has no identity. Sub-identities do not share the judgement of
+        /// their parent, so one judged identity cannot back several members.
+        fn judgement(account: &AccountId) -> Option<IdentityJudgement>;
+
+        /// The parent of `account` if it is a sub-identity
+        fn super_of(account: &AccountId) -> Option<AccountId>;
+
+        /// Gives `account` an identity with the given display name, as
+        /// benchmarks need members to join.
+        #[cfg(feature = "runtime-benchmarks")]
+        fn ensure_identity(account: &AccountId, display: &[u8]) -> DispatchResult;
+
+        /// Has `registrar` give the identity of `account` a positive
+        /// judgement, registering `registrar` first if it is not one yet.
+        #[cfg(feature = "runtime-benchmarks")]
+        fn ensure_judgement(registrar: &AccountId, account: &AccountId) -> DispatchResult;
+    }
+
+    /// Seeds the identities of genesis members. Only the genesis build of the
+    /// pallet calls it.
+    pub trait IdentitySeeder<AccountId> {
+        /// Gives `account` an identity with the given display name
+        fn seed_identity(account: &AccountId, display: &[u8]) -> DispatchResult;
+
+        /// Has `registrar` give the identity of `account` a positive
+        /// judgement, registering `registrar` first if it is not one yet
+        fn seed_judgement(registrar: &AccountId, account: &AccountId) -> DispatchResult;
+    }
+
+    /// For runtimes that do not seed identities at genesis.
+    impl<AccountId> IdentitySeeder<AccountId> for () {
+        fn seed_identity(_: &AccountId, _: &[u8]) -> DispatchResult {
+            Err(DispatchError::Other("identities are not seeded"))
+        }
+
+        fn seed_judgement(_: &AccountId, _: &AccountId) -> DispatchResult {
+            Err(DispatchError::Other("identities are not seeded"))
+        }
     }
 
     /// The pallet's configuration trait.
@@ -82,34 +226,133 @@ pub mod pallet {
     /// These types are defined generically and made concrete when the pallet is
     /// declared in the `runtime/src/lib.rs` file of your chain.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config:
+        frame_system::Config + SendTransactionTypes<Call<Self>> + SigningTypes
+    {
         /// The overarching runtime event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type IdentityProvider: IdentityProvider<Self::AccountId>;
+        /// Gives the accounts in the `identities` of the genesis config an
+        /// identity
+        type IdentitySeeder: IdentitySeeder<Self::AccountId>;
         type Currency: ReservableCurrency<Self::AccountId>;
 
-        /// The amount of funds that is required to have skin in a game
+        /// The amount of funds that is required to have skin in a game,
+        /// unless `ParameterOrigin` set another entry fee
         #[pallet::constant]
         type BasicDeposit: Get<BalanceOf<Self>>;
 
-        /// The length of reveal phase
+        /// The length of reveal phase, unless `ParameterOrigin` set another
         #[pallet::constant]
         type RevealLength: Get<BlockNumberFor<Self>>;
 
-        /// Minimum length of proposal
+        /// Minimum length of proposal, unless `ParameterOrigin` set another
         #[pallet::constant]
         type MinLength: Get<BlockNumberFor<Self>>;
 
-        /// Minimum length of proposal
+        /// The voting credits of members. Committing `n` votes costs `n²`
+        /// credits.
+        type VoteCredit: Parameter
+            + Member
+            + MaxEncodedLen
+            + AtLeast32BitUnsigned
+            + Copy
+            + Default
+            + MaybeSerializeDeserialize;
+
+        /// The credits a member is given on joining, and the most they can
+        /// hold. `ParameterOrigin` can lower it.
         #[pallet::constant]
-        type MaxVotingTokens: Get<u8>;
+        type MaxVotingTokens: Get<Self::VoteCredit>;
 
         /// Maximum number of proposals allowed to be active in parallel.
+        /// `ParameterOrigin` can lower it, the storage stays bounded by it.
         #[pallet::constant]
         type MaxProposals: Get<ProposalIndex>;
 
-        type Public: IdentifyAccount<AccountId = Self::AccountId>;
-        type Signature: Verify<Signer = Self::Public> + Member + Decode + Encode + TypeInfo;
+        /// The funds a proposer reserves for each proposal until it is closed
+        #[pallet::constant]
+        type ProposalBond: Get<BalanceOf<Self>>;
+
+        /// The part of the bond slashed into the pot when the note is
+        /// disapproved
+        #[pallet::constant]
+        type BondSlash: Get<Perbill>;
+
+        /// How long the outcome of a closed proposal is kept before it is
+        /// pruned
+        #[pallet::constant]
+        type ResultRetention: Get<BlockNumberFor<Self>>;
+
+        /// The part of the reserved funds taken from a voter who did not reveal
+        #[pallet::constant]
+        type NonRevealPenalty: Get<Perbill>;
+
+        /// How much a voter on the losing side is slashed
+        type SlashPolicy: slashing::SlashPolicy<BalanceOf<Self>>;
+
+        /// Which options of a proposal win when it is closed
+        type OutcomeRule: outcome::OutcomeRule<Self>;
+
+        /// The judgement the identity of a member needs at least. Members
+        /// whose identity falls below it are suspended.
+        #[pallet::constant]
+        type MinJudgement: Get<IdentityJudgement>;
+
+        /// The origin that can redistribute or burn the funds held in the pot
+        type PotOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The origin that can assign members to a viewpoint cluster, e.g.
+        /// from clusters derived from their past votes
+        type ViewpointOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// How long a member waits after its viewpoint changed before it can
+        /// declare another one
+        #[pallet::constant]
+        type ViewpointCooldown: Get<BlockNumberFor<Self>>;
+
+        /// The origin that can cancel a proposal in any phase, e.g. an abusive
+        /// note
+        type CancelOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The origin that can remove members, force the phases of a proposal
+        /// to end and correct the balance of a member
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The origin that can set the voting parameters on chain
+        type ParameterOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// What happens to the voting tokens of a commit that was not revealed
+        #[pallet::constant]
+        type UnrevealedTokens: Get<TokenPolicy>;
+
+        /// Whether spent credits are refunded when proposals close or
+        /// replenished in epochs
+        #[pallet::constant]
+        type CreditPolicy: Get<CreditPolicy<BlockNumberFor<Self>, Self::VoteCredit>>;
+
+        /// Maximum number of voters that can commit on a single proposal.
+        /// Closing a reveal phase with that many voters has to fit in an
+        /// extrinsic, which `integrity_test` checks.
+        #[pallet::constant]
+        type MaxVotersPerProposal: Get<u32>;
+
+        /// Maximum number of unrevealed commits reaped when a proposal is
+        /// closed, and in a single `reap_unrevealed` call. Also bounds the
+        /// commits and votes refunded in a single `cancel_proposal` or
+        /// `refund_cancelled` call.
+        #[pallet::constant]
+        type ReapLimit: Get<u32>;
+
+        /// Maximum number of members the pot is shared between in a single
+        /// `spend_pot` call
+        #[pallet::constant]
+        type MaxBeneficiaries: Get<u32>;
+
+        /// Maximum number of phases closed in `on_initialize` of a block. The
+        /// ones past it are carried over to the next blocks.
+        #[pallet::constant]
+        type MaxClosesPerBlock: Get<u32>;
 
         /// The council's pallet id, used for deriving its sovereign account ID.
         #[pallet::constant]
@@ -118,8 +361,70 @@ pub mod pallet {
         /// A type representing the weights required by the dispatchables of
         /// this pallet.
         type WeightInfo: WeightInfo;
+
+        /// The overarching origin type. Approved proposals dispatch their call
+        /// with [`RawOrigin::Approved`].
+        type RuntimeOrigin: From<RawOrigin<Self::Hash>>;
+
+        /// The call a proposal can carry
+        type RuntimeCall: Parameter
+            + Dispatchable<
+                RuntimeOrigin = <Self as Config>::RuntimeOrigin,
+                PostInfo = PostDispatchInfo,
+            > + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        /// Maximum length of the encoded call of a proposal
+        #[pallet::constant]
+        type MaxCallLen: Get<u32>;
+
+        /// Maximum weight of the call of a proposal
+        #[pallet::constant]
+        type MaxCallWeight: Get<Weight>;
+
+        /// Blocks between two imports of the feed by the offchain worker
+        #[pallet::constant]
+        type ImportInterval: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of posts submitted in a single import
+        #[pallet::constant]
+        type MaxImportBatch: Get<u32>;
+
+        /// Maximum number of candidates waiting for review
+        #[pallet::constant]
+        type MaxCandidates: Get<u32>;
+
+        /// Priority of the unsigned transactions importing candidates
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        /// The keys importers sign the imports of the offchain worker with
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// How long a candidate waits for review before anyone can remove it
+        #[pallet::constant]
+        type CandidateLifetime: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of members delegating to a member, and of delegated
+        /// votes given when a member reveals
+        #[pallet::constant]
+        type MaxDelegators: Get<u32>;
+
+        /// Maximum length of a chain of delegations
+        #[pallet::constant]
+        type MaxDelegationDepth: Get<u32>;
+    }
+
+    /// Origin of the calls dispatched by approved proposals.
+    #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum RawOrigin<Hash> {
+        /// The proposal with the given hash has been approved
+        Approved(Hash),
     }
 
+    #[pallet::origin]
+    pub type Origin<T> = RawOrigin<<T as frame_system::Config>::Hash>;
+
     #[pallet::storage]
     pub type Proposals<T: Config> =
         StorageValue<_, BoundedVec<T::Hash, T::MaxProposals>, ValueQuery>;
@@ -128,16 +433,149 @@ pub mod pallet {
     pub type ProposalData<T: Config> =
         StorageMap<_, Identity, T::Hash, Proposal<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>>;
 
+    /// Bonds reserved by proposers for their open proposals.
+    #[pallet::storage]
+    pub type ProposalBonds<T: Config> = StorageMap<_, Identity, T::Hash, BalanceOf<T>>;
+
+    /// Cancelled proposals whose commits and votes are not all refunded yet.
+    #[pallet::storage]
+    pub type CancelledProposals<T: Config> = StorageMap<_, Identity, T::Hash, ()>;
+
+    /// Encoded calls dispatched when their proposal is approved.
+    #[pallet::storage]
+    pub type ProposalCalls<T: Config> =
+        StorageMap<_, Identity, T::Hash, BoundedVec<u8, T::MaxCallLen>>;
+
+    /// The content each proposal annotates, kept as long as its outcome.
+    #[pallet::storage]
+    pub type NoteTargets<T: Config> = StorageMap<_, Identity, T::Hash, NoteTargetOf<T>>;
+
+    /// Proposals annotating the same content, keyed by
+    /// [`Pallet::target_key`] of their target and by proposal hash.
+    #[pallet::storage]
+    pub type TargetNotes<T: Config> = StorageDoubleMap<_, Identity, T::Hash, Identity, T::Hash, ()>;
+
+    /// Posts imported by the offchain worker that no note annotates yet,
+    /// keyed by [`Pallet::target_key`]. At most `MaxCandidates` are kept.
+    #[pallet::storage]
+    pub type Candidates<T: Config> = CountedStorageMap<_, Identity, T::Hash, CandidateOf<T>>;
+
+    /// The block from which the next import of the feed is accepted.
+    #[pallet::storage]
+    pub type NextImportAt<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Accounts whose keys can sign the imports of the offchain worker, set by
+    /// `AdminOrigin`.
+    #[pallet::storage]
+    pub type Importers<T: Config> = StorageMap<_, Identity, T::AccountId, ()>;
+
+    /// Outcomes of closed proposals, kept for `ResultRetention` blocks.
+    #[pallet::storage]
+    pub type ProposalResults<T: Config> =
+        StorageMap<_, Identity, T::Hash, ProposalResult<BlockNumberFor<T>, BalanceOf<T>>>;
+
     #[pallet::storage]
     pub type Commits<T: Config> =
-        StorageDoubleMap<_, Identity, T::AccountId, Identity, T::Hash, Commit<T::Signature>>;
+        StorageDoubleMap<_, Identity, T::AccountId, Identity, T::Hash, CommitOf<T>>;
+
+    /// Revealed votes of a proposal, removed when it is closed.
+    #[pallet::storage]
+    pub type Votes<T: Config> =
+        StorageDoubleMap<_, Identity, T::Hash, Identity, T::AccountId, VoteRecordOf<T>>;
+
+    /// Accounts that committed on a proposal and have not revealed yet.
+    #[pallet::storage]
+    pub type Committers<T: Config> =
+        StorageDoubleMap<_, Identity, T::Hash, Identity, T::AccountId, ()>;
+
+    /// Phases of proposals that end at a given block, closed automatically in
+    /// `on_initialize`.
+    #[pallet::storage]
+    pub type Deadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(T::Hash, Phase), MaxDeadlines<T>>,
+        ValueQuery,
+    >;
+
+    /// The earliest block whose deadlines were not all processed, because
+    /// more than `MaxClosesPerBlock` phases ended by then. Unset when
+    /// `on_initialize` is up to date.
+    #[pallet::storage]
+    pub type DeadlineCursor<T: Config> = StorageValue<_, BlockNumberFor<T>>;
+
+    /// Bookkeeping of the funds slashed into the pot.
+    #[pallet::storage]
+    pub type Pot<T: Config> = StorageValue<_, PotLedger<BalanceOf<T>>, ValueQuery>;
+
+    /// Funds slashed into the pot for a proposal, kept as long as the proposal
+    /// or its outcome.
+    #[pallet::storage]
+    pub type PotInflows<T: Config> = StorageMap<_, Identity, T::Hash, BalanceOf<T>, ValueQuery>;
 
     #[pallet::storage]
     pub type Members<T: Config> =
-        CountedStorageMap<_, Identity, T::AccountId, VoterBalance<BalanceOf<T>>, ValueQuery>;
+        CountedStorageMap<_, Identity, T::AccountId, VoterBalanceOf<T>, ValueQuery>;
+
+    /// The members each member delegates its credits to, per topic.
+    #[pallet::storage]
+    pub type Delegations<T: Config> =
+        StorageMap<_, Identity, T::AccountId, Delegates<T::AccountId>, ValueQuery>;
+
+    /// Members delegating their credits to a member, with the topic they
+    /// delegate.
+    #[pallet::storage]
+    pub type Delegators<T: Config> =
+        StorageMap<_, Identity, T::AccountId, DelegatorsOf<T>, ValueQuery>;
+
+    /// Track records of current and former members.
+    #[pallet::storage]
+    pub type Reputations<T: Config> =
+        StorageMap<_, Identity, T::AccountId, ReputationOf<T>, ValueQuery>;
+
+    /// Members whose identity is no longer judged well enough. They cannot
+    /// propose or commit until their judgement is restored.
+    #[pallet::storage]
+    pub type Suspended<T: Config> = StorageMap<_, Identity, T::AccountId, BlockNumberFor<T>>;
+
+    /// The last member whose judgement was checked in `on_idle`, where the
+    /// next check resumes.
+    #[pallet::storage]
+    pub type JudgementCursor<T: Config> = StorageValue<_, T::AccountId>;
+
+    /// The epoch the credits of a member were last replenished in.
+    #[pallet::storage]
+    pub type CreditEpochs<T: Config> = StorageMap<_, Identity, T::AccountId, Epoch, ValueQuery>;
+
+    /// The last epoch whose credits were replenished for every member in
+    /// `on_idle`.
+    #[pallet::storage]
+    pub type ReplenishedEpoch<T: Config> = StorageValue<_, Epoch, ValueQuery>;
+
+    /// The last member whose credits were replenished in `on_idle`, where the
+    /// replenishment of the current epoch resumes.
+    #[pallet::storage]
+    pub type ReplenishCursor<T: Config> = StorageValue<_, T::AccountId>;
+
+    /// Viewpoint clusters of members, declared by themselves or assigned by
+    /// `ViewpointOrigin`. Used by outcome rules that need agreement across
+    /// clusters.
+    #[pallet::storage]
+    pub type Viewpoints<T: Config> = StorageMap<_, Identity, T::AccountId, Viewpoint>;
+
+    /// The block in which the viewpoint of a member last changed, from which
+    /// `ViewpointCooldown` runs.
+    #[pallet::storage]
+    pub type ViewpointChanges<T: Config> = StorageMap<_, Identity, T::AccountId, BlockNumberFor<T>>;
+
+    /// Voting parameters set by `ParameterOrigin` in place of the runtime
+    /// configuration.
+    #[pallet::storage]
+    pub type Parameters<T: Config> = StorageValue<_, VotingParametersOf<T>, ValueQuery>;
 
     #[pallet::pallet]
-    #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Events that functions in this pallet can emit.
@@ -187,11 +625,174 @@ pub mod pallet {
         /// The voting phase was closed
         ClosedReveal {
             proposal_hash: T::Hash,
-            yes: MemberCount,
-            no: MemberCount,
+            tally: Tally,
+            breakdown: Breakdown,
+            winners: Winners,
             revealed: MemberCount,
             payout: BalanceOf<T>,
         },
+        /// The outcome of a closed proposal has been pruned
+        ResultPruned(T::Hash),
+        /// A phase could not be closed in `on_initialize`. It is queued again
+        /// at `retry_at` if there is room, and can be closed manually.
+        AutoCloseFailed {
+            proposal_hash: T::Hash,
+            phase: Phase,
+            error: DispatchError,
+            retry_at: Option<BlockNumberFor<T>>,
+        },
+        /// The call of an approved proposal has been dispatched
+        CallDispatched {
+            proposal_hash: T::Hash,
+        },
+        /// The call of an approved proposal could not be dispatched
+        CallFailed {
+            proposal_hash: T::Hash,
+            error: DispatchError,
+        },
+        /// A proposer reserved the bond of a proposal
+        BondReserved {
+            proposal_hash: T::Hash,
+            proposer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The bond of a closed proposal was given back to its proposer
+        BondReturned {
+            proposal_hash: T::Hash,
+            proposer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Part of the bond of a disapproved proposal was slashed into the pot
+        BondSlashed {
+            proposal_hash: T::Hash,
+            proposer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A commit that was not revealed has been removed and the voter
+        /// penalised
+        Reaped {
+            account: T::AccountId,
+            proposal_hash: T::Hash,
+            slashed: BalanceOf<T>,
+        },
+        /// Funds held in the pot have been burned
+        PotBurned {
+            amount: BalanceOf<T>,
+        },
+        /// Funds held in the pot have been shared between members
+        PotRedistributed {
+            amount: BalanceOf<T>,
+            beneficiaries: u32,
+        },
+        /// The identity of a member fell below `MinJudgement`
+        MemberSuspended {
+            account: T::AccountId,
+        },
+        /// The identity of a suspended member is judged well enough again
+        MemberReinstated {
+            account: T::AccountId,
+        },
+        /// The track record of a member changed after a proposal was closed,
+        /// a late reveal or a reaped commit
+        ReputationUpdated {
+            account: T::AccountId,
+            reputation: ReputationOf<T>,
+        },
+        /// The viewpoint cluster of a member was declared, assigned or
+        /// cleared
+        ViewpointSet {
+            account: T::AccountId,
+            viewpoint: Option<Viewpoint>,
+        },
+        /// A new proposal annotates the given content
+        Targeted {
+            proposal_hash: T::Hash,
+            target: NoteTargetOf<T>,
+        },
+        /// A post of the feed was imported as a candidate for review
+        CandidateImported {
+            key: T::Hash,
+            target: NoteTargetOf<T>,
+        },
+        /// A candidate was dismissed by a member without proposing a note
+        CandidateDismissed {
+            key: T::Hash,
+            account: T::AccountId,
+        },
+        /// A candidate nobody proposed a note about within
+        /// `CandidateLifetime` was removed
+        CandidateExpired {
+            key: T::Hash,
+        },
+        /// An account was allowed or no longer allowed to sign imports
+        ImporterSet {
+            account: T::AccountId,
+            allowed: bool,
+        },
+        /// A member delegated its credits on a topic
+        Delegated {
+            delegator: T::AccountId,
+            delegate: T::AccountId,
+            topic: Topic,
+        },
+        /// A delegation was withdrawn, or removed when one of its members left
+        Undelegated {
+            delegator: T::AccountId,
+            topic: Topic,
+        },
+        /// Votes were given on behalf of a delegator when its delegate revealed
+        DelegatedVote {
+            delegator: T::AccountId,
+            delegate: T::AccountId,
+            proposal_hash: T::Hash,
+            number: T::VoteCredit,
+        },
+        /// A credit epoch started, the credits of members are replenished
+        EpochStarted {
+            epoch: Epoch,
+        },
+        /// The credits of a member were replenished for an epoch
+        CreditsReplenished {
+            account: T::AccountId,
+            epoch: Epoch,
+            credits: T::VoteCredit,
+        },
+        /// A proposal nobody committed on was withdrawn by its proposer
+        Withdrawn {
+            proposal_hash: T::Hash,
+            proposer: T::AccountId,
+        },
+        /// A proposal was cancelled and the credits of its voters refunded,
+        /// up to `ReapLimit` of them
+        Cancelled {
+            proposal_hash: T::Hash,
+            refunded: u32,
+        },
+        /// More voters of a cancelled proposal had their credits refunded
+        Refunded {
+            proposal_hash: T::Hash,
+            refunded: u32,
+        },
+        /// A member was removed by `AdminOrigin`, its pending commits and the
+        /// votes it revealed on active proposals were dropped
+        MemberRemoved {
+            account: T::AccountId,
+            cashout: BalanceOf<T>,
+        },
+        /// A phase of a proposal was ended by `AdminOrigin` before its deadline
+        ForceClosed {
+            proposal_hash: T::Hash,
+            phase: Phase,
+        },
+        /// The balance of a member was set by `AdminOrigin`
+        MemberBalanceSet {
+            account: T::AccountId,
+            balance: VoterBalanceOf<T>,
+        },
+        /// A voting parameter was set by `ParameterOrigin`
+        ParameterSet {
+            parameter: VotingParameterOf<T>,
+        },
     }
 
     /// Errors that can be returned by this pallet.
@@ -229,11 +830,15 @@ pub mod pallet {
         TooManyProposals,
         /// The given length bound for the proposal was too low.
         WrongProposalLength,
-        /// Not enough funds to join the voting council
+        /// Not enough funds to join the voting council or to reserve the bond
+        /// of a proposal
         NotEnoughFunds,
+        /// The member has fewer funds of its own reserved than the ones that
+        /// would be given back
+        ReserveShortfall,
         /// Proposal Ended
         ProposalEnded,
-        ///
+        /// Not enough voting tokens left to commit this many votes
         NotEnoughVotingTokens,
         /// Voting phase ended
         VoteEnded,
@@ -245,21 +850,215 @@ pub mod pallet {
         RevealNotStarted,
         /// No commit has been submitted
         NoCommit,
-        /// Could not verify signature of a commit
-        SignatureInvalid,
+        /// The revealed vote and salt do not match the commitment
+        CommitmentMismatch,
         /// The voter is in the middle of vote
         InMotion,
         /// Proposal is still going
         NotFinished,
+        /// The outcome of the proposal is still retained
+        RetentionNotExpired,
+        /// There is no archived outcome for the proposal
+        ResultMissing,
+        /// A proposal needs at least two rating options
+        TooFewOptions,
+        /// The proposal has no rating option with this index
+        InvalidOption,
+        /// The encoded call is longer than `MaxCallLen`
+        CallTooLong,
+        /// The call weighs more than `MaxCallWeight`
+        CallTooHeavy,
+        /// The call of the proposal cannot be decoded anymore
+        UndecodableCall,
+        /// There can only be `MaxVotersPerProposal` voters on a proposal
+        TooManyVoters,
+        /// The quadratic cost of the votes does not fit in a `VoteCredit`
+        CostOverflow,
+        /// The pot does not hold that many funds that were not paid out
+        InsufficientPot,
+        /// Funds cannot be redistributed to nobody
+        NoBeneficiaries,
+        /// The identity of the account is not judged well enough
+        InsufficientJudgement,
+        /// The parent identity of the account is already a member
+        ParentIsMember,
+        /// The member is suspended until its identity is judged well enough
+        MemberSuspended,
+        /// The judgement of the member still matches its suspension
+        JudgementUnchanged,
+        /// There are only `MAX_VIEWPOINTS` viewpoint clusters
+        InvalidViewpoint,
+        /// The viewpoint changed less than `ViewpointCooldown` blocks ago
+        ViewpointCooldown,
+        /// A note target needs a platform
+        InvalidTarget,
+        /// The feed was already imported less than `ImportInterval` blocks ago
+        ImportTooEarly,
+        /// The import was signed for another import of the feed
+        StaleImport,
+        /// The account is not allowed to sign imports
+        NotImporter,
+        /// The candidate was imported less than `CandidateLifetime` blocks ago
+        CandidateNotExpired,
+        /// The proposal was not cancelled, or all its voters were refunded
+        NotCancelled,
+        /// The voters of the cancelled proposal are still being refunded
+        RefundPending,
+        /// There is no candidate with this key
+        CandidateMissing,
+        /// A member cannot delegate to itself
+        SelfDelegation,
+        /// The delegate delegates back to the member, directly or not
+        DelegationCycle,
+        /// The chain of delegations would be longer than `MaxDelegationDepth`
+        DelegationTooDeep,
+        /// A member can only delegate `MAX_DELEGATIONS` topics separately
+        TooManyDelegations,
+        /// The delegate already has `MaxDelegators` delegators
+        TooManyDelegators,
+        /// The member does not delegate this topic
+        NotDelegated,
+        /// The credits of the member were already replenished in this epoch
+        CreditsUpToDate,
+        /// Only the proposer can withdraw its proposal
+        NotProposer,
+        /// Members already committed on the proposal
+        ProposalHasCommits,
+        /// The parameter is zero or above its bound in the runtime
+        /// configuration
+        InvalidParameter,
+        /// `MaxDeadlines` phases already end in the block this one would end
+        /// in
+        TooManyDeadlines,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::start_epoch(now);
+            Self::process_deadlines(now)
+        }
+
+        fn on_idle(_now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let weight = Self::replenish_members(limit);
+            weight.saturating_add(Self::check_judgements(limit.saturating_sub(weight)))
+        }
+
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            match Self::import_feed(now) {
+                Ok(imported) if imported > 0 => {
+                    log::info!(target: LOG_TARGET, "submitted {} candidates from the feed", imported)
+                }
+                Ok(_) | Err(ImportError::NoFeed) => {}
+                Err(error) => log::warn!(target: LOG_TARGET, "feed not imported: {:?}", error),
+            }
+        }
+
+        fn integrity_test() {
+            // the weights of the bounded batches are declared up front, they
+            // have to fit in an extrinsic and the closes of a block in it
+            let weights = T::BlockWeights::get();
+            let max_extrinsic =
+                weights.get(DispatchClass::Normal).max_extrinsic.unwrap_or(weights.max_block);
+            let reap = T::WeightInfo::reap_unrevealed(T::ReapLimit::get());
+            let close = T::WeightInfo::close_reveal(T::MaxVotersPerProposal::get())
+                .max(T::WeightInfo::force_close_reveal(
+                    T::MaxVotersPerProposal::get(),
+                ))
+                .saturating_add(reap)
+                .saturating_add(T::MaxCallWeight::get());
+            assert!(
+                close.all_lte(max_extrinsic),
+                "closing `MaxVotersPerProposal` reveals does not fit in an extrinsic"
+            );
+            assert!(
+                close
+                    .saturating_mul(T::MaxClosesPerBlock::get().into())
+                    .all_lte(weights.max_block),
+                "`MaxClosesPerBlock` closes do not fit in a block"
+            );
+            for (call, weight) in [
+                ("reap_unrevealed", reap),
+                (
+                    "spend_pot",
+                    T::WeightInfo::spend_pot(T::MaxBeneficiaries::get()),
+                ),
+                (
+                    "cancel_proposal",
+                    T::WeightInfo::cancel_proposal(T::ReapLimit::get()),
+                ),
+                (
+                    "refund_cancelled",
+                    T::WeightInfo::refund_cancelled(T::ReapLimit::get()),
+                ),
+            ] {
+                assert!(
+                    weight.all_lte(max_extrinsic),
+                    "`{}` does not fit in an extrinsic",
+                    call
+                );
+            }
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Only imports signed by an importer for the next import of the feed
+        /// are accepted.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let Call::submit_candidates { payload, signature } = call else {
+                return InvalidTransaction::Call.into();
+            };
+            if payload.candidates.is_empty()
+                || payload.candidates.iter().any(|t| t.platform.is_empty())
+            {
+                return InvalidTransaction::Call.into();
+            }
+            let next = <NextImportAt<T>>::get();
+            if payload.import_at != next {
+                return InvalidTransaction::Stale.into();
+            }
+            if frame_system::Pallet::<T>::block_number() < next {
+                return InvalidTransaction::Future.into();
+            }
+            if !<Importers<T>>::contains_key(payload.public.clone().into_account()) {
+                return InvalidTransaction::BadSigner.into();
+            }
+            if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+                return InvalidTransaction::BadProof.into();
+            }
+
+            ValidTransaction::with_tag_prefix("VotingImport")
+                .priority(T::UnsignedPriority::get())
+                .and_provides(next)
+                .longevity(T::ImportInterval::get().saturated_into::<u64>().max(1))
+                .propagate(true)
+                .build()
+        }
+    }
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
-        _phantom: core::marker::PhantomData<T>,
+        /// Accounts given an identity with the display name through
+        /// `IdentitySeeder`, before the members are checked
+        pub identities: Vec<(T::AccountId, Vec<u8>)>,
+        /// The registrar that judges the seeded identities, if any
+        pub registrar: Option<T::AccountId>,
+        /// Initial members with their voting credits and the deposit they
+        /// reserve, which is at least `BasicDeposit`
+        pub members: Vec<(T::AccountId, T::VoteCredit, BalanceOf<T>)>,
+        /// Proposals open at genesis with their proposer, title, target
+        /// platform and content, and length
+        #[allow(clippy::type_complexity)]
+        pub proposals: Vec<(T::AccountId, Vec<u8>, GenesisTargetOf<T>, BlockNumberFor<T>)>,
+        /// Proposals whose reveal phase is open at genesis, with their
+        /// proposer, title and target platform and content
+        pub revealing: Vec<(T::AccountId, Vec<u8>, GenesisTargetOf<T>)>,
+        /// Accounts allowed to sign the imports of the offchain worker
+        pub importers: Vec<T::AccountId>,
     }
 
     #[pallet::genesis_build]
@@ -271,6 +1070,98 @@ pub mod pallet {
             if T::Currency::free_balance(&account_id) < min {
                 let _ = T::Currency::make_free_balance_be(&account_id, min);
             }
+
+            for (account, display) in self.identities.iter() {
+                T::IdentitySeeder::seed_identity(account, display)
+                    .expect("genesis identity can be seeded");
+                if let Some(registrar) = &self.registrar {
+                    T::IdentitySeeder::seed_judgement(registrar, account)
+                        .expect("genesis identity can be judged");
+                }
+            }
+
+            for (account, voting_tokens, deposit) in self.members.iter() {
+                assert!(
+                    T::IdentityProvider::check_existence(account),
+                    "genesis member has no identity"
+                );
+                assert!(
+                    Pallet::<T>::is_judged(account),
+                    "genesis member identity is below MinJudgement"
+                );
+                assert!(!Pallet::<T>::is_member(account), "duplicate genesis member");
+                assert!(
+                    *deposit >= T::BasicDeposit::get(),
+                    "genesis member deposit is below BasicDeposit"
+                );
+                assert!(
+                    *voting_tokens <= T::MaxVotingTokens::get(),
+                    "genesis member has more than MaxVotingTokens"
+                );
+                T::Currency::reserve(account, *deposit)
+                    .expect("genesis member can reserve its deposit");
+                <Members<T>>::insert(
+                    account,
+                    VoterBalance {
+                        voting_tokens: *voting_tokens,
+                        reserved_balance: *deposit,
+                    },
+                );
+            }
+
+            for (proposer, title, target, length) in self.proposals.iter() {
+                Self::propose(proposer, title, target, *length);
+            }
+
+            // The commit phase of these proposals ends right away instead of
+            // at its deadline.
+            for (proposer, title, target) in self.revealing.iter() {
+                let now = frame_system::Pallet::<T>::block_number();
+                let proposal = Self::propose(proposer, title, target, T::MinLength::get());
+                <Pallet<T>>::unschedule_deadline(now + T::MinLength::get(), proposal);
+                <ProposalData<T>>::mutate(proposal, |data| {
+                    if let Some(data) = data {
+                        data.poll_end = now;
+                    }
+                });
+                <Pallet<T>>::do_close_vote(proposal).expect("genesis proposal can be revealed");
+            }
+
+            for importer in self.importers.iter() {
+                <Importers<T>>::insert(importer, ());
+            }
+        }
+    }
+
+    impl<T: Config> GenesisConfig<T> {
+        /// Creates a genesis proposal and returns its hash
+        fn propose(
+            proposer: &T::AccountId,
+            title: &[u8],
+            (platform, content): &GenesisTargetOf<T>,
+            length: BlockNumberFor<T>,
+        ) -> T::Hash {
+            let title =
+                Data::Raw(title.to_vec().try_into().expect("genesis proposal title is too long"));
+            let proposal = T::Hashing::hash_of(&title);
+            let target = NoteTarget {
+                platform: platform
+                    .clone()
+                    .try_into()
+                    .expect("genesis proposal platform is too long"),
+                content: *content,
+                timestamp: None,
+            };
+            <Pallet<T>>::create_proposal(
+                frame_system::RawOrigin::Signed(proposer.clone()).into(),
+                Box::new(title),
+                target,
+                length,
+                None,
+                None,
+            )
+            .expect("genesis proposal is valid");
+            proposal
         }
     }
 
@@ -308,29 +1199,42 @@ pub mod pallet {
                 T::IdentityProvider::check_existence(&signer),
                 Error::<T>::NoIdentity
             );
+            ensure!(Self::is_judged(&signer), Error::<T>::InsufficientJudgement);
+            if let Some(parent) = T::IdentityProvider::super_of(&signer) {
+                ensure!(!Self::is_member(&parent), Error::<T>::ParentIsMember);
+            }
 
             // check if the account has enough money to deposit
+            let entry_fee = Self::entry_fee();
             ensure!(
-                T::Currency::can_reserve(&signer, T::BasicDeposit::get()),
+                T::Currency::can_reserve(&signer, entry_fee),
                 Error::<T>::NotEnoughFunds
             );
 
-            T::Currency::reserve(&signer, T::BasicDeposit::get())?;
+            T::Currency::reserve(&signer, entry_fee)?;
 
-            // deposit 100 voting tokens to the voter
-            Self::deposit_votes(&signer, T::MaxVotingTokens::get());
-
-            // reserve the fixed amount specified in the config
-            Self::set_reserved_balance(&signer, T::BasicDeposit::get());
+            // give the voter its credits and record the reserved entry fee
+            <Members<T>>::insert(
+                &signer,
+                VoterBalance {
+                    voting_tokens: Self::max_voting_tokens(),
+                    reserved_balance: entry_fee,
+                },
+            );
+            <CreditEpochs<T>>::insert(&signer, Self::current_epoch());
 
             Self::deposit_event(Event::<T>::Joined(signer));
 
             Ok(())
         }
 
+        /// Leaves the committee, giving back the reserved funds. The
+        /// delegations of the member and to the member are removed.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::leave_committee())]
-        pub fn leave_committee(origin: OriginFor<T>) -> DispatchResult {
+        #[pallet::weight(T::WeightInfo::leave_committee(
+            T::MaxDelegators::get().saturating_add(types::MAX_DELEGATIONS)
+        ))]
+        pub fn leave_committee(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let signer = ensure_signed(origin)?;
             // check if signer has identity
             ensure!(
@@ -340,70 +1244,124 @@ pub mod pallet {
 
             let active_votes = <Commits<T>>::iter_prefix_values(signer.clone()).count();
             ensure!(active_votes == 0, Error::<T>::InMotion);
+            // so are the votes a delegate gave on its behalf
+            ensure!(!Self::has_votes(&signer), Error::<T>::InMotion);
 
-            // find the exact amount of reserved funds that need to be returned to the free
-            // balance
-            let reserved_balance = <Members<T>>::get(signer.clone()).reserved_balance;
-            let balance = T::Currency::unreserve(&signer, reserved_balance);
-            // remove entries
-            <Members<T>>::remove(signer.clone());
+            let (balance, delegations) = Self::remove_member(&signer);
             Self::deposit_event(Event::<T>::Left {
                 account: signer,
                 cashout: balance,
             });
-            Ok(())
+            Ok(Some(T::WeightInfo::leave_committee(delegations)).into())
         }
 
         #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::create_proposal())]
+        #[pallet::weight(T::WeightInfo::create_proposal(T::MaxProposals::get()))]
         pub fn create_proposal(
             origin: OriginFor<T>,
             community_note: Box<Data>,
+            target: NoteTargetOf<T>,
             duration: BlockNumberFor<T>,
+            options: Option<RatingOptions>,
+            call: Option<Box<<T as Config>::RuntimeCall>>,
         ) -> DispatchResult {
             let signer = ensure_signed(origin)?;
 
-            if duration < T::MinLength::get() {
+            let options = options.unwrap_or_else(types::default_options);
+            ensure!(options.len() >= 2, Error::<T>::TooFewOptions);
+            ensure!(!target.platform.is_empty(), Error::<T>::InvalidTarget);
+
+            if duration < Self::min_length() {
                 ensure!(false, Error::<T>::WrongProposalLength);
             }
 
             // check if signer is a member already
             ensure!(Self::is_member(&signer), Error::<T>::NotMember);
+            ensure!(
+                !<Suspended<T>>::contains_key(&signer),
+                Error::<T>::MemberSuspended
+            );
             let length_res = <Proposals<T>>::decode_len();
             if let Some(length) = length_res {
-                if length == T::MaxProposals::get() as usize {
+                if length >= Self::max_proposals() as usize {
                     ensure!(false, Error::<T>::TooManyProposals);
                 }
             }
 
+            let call = match call {
+                Some(call) => {
+                    ensure!(
+                        call.get_dispatch_info().weight.all_lte(T::MaxCallWeight::get()),
+                        Error::<T>::CallTooHeavy
+                    );
+                    let encoded = BoundedVec::<u8, T::MaxCallLen>::try_from(call.encode());
+                    ensure!(encoded.is_ok(), Error::<T>::CallTooLong);
+                    encoded.ok()
+                }
+                None => None,
+            };
+
             let proposal_hash = T::Hashing::hash_of(&community_note);
             let (exist, _) = Self::proposal_exist(&proposal_hash);
             ensure!(!exist, Error::<T>::DuplicateProposal);
+            ensure!(
+                !<ProposalResults<T>>::contains_key(proposal_hash)
+                    && !<CancelledProposals<T>>::contains_key(proposal_hash),
+                Error::<T>::DuplicateProposal
+            );
+            let bond = T::ProposalBond::get();
+            ensure!(
+                T::Currency::can_reserve(&signer, bond),
+                Error::<T>::NotEnoughFunds
+            );
+            let end = duration + frame_system::Pallet::<T>::block_number();
+            ensure!(Self::can_schedule(end), Error::<T>::TooManyDeadlines);
             ensure!(
                 <Proposals<T>>::try_append(proposal_hash).is_ok(),
                 Error::<T>::TooManyProposals
             );
-
-            let end = duration + frame_system::Pallet::<T>::block_number();
+            T::Currency::reserve(&signer, bond)?;
 
             let proposal = Proposal {
                 title: *community_note,
                 proposer: signer.clone(),
-                ayes: 0,
-                nays: 0,
+                tally: Tally::truncate_from(vec![0; options.len()]),
+                options,
                 poll_end: end,
                 reveal_end: None,
-                votes: Vec::new(),
-                revealed: Vec::new(),
+                voters: 0,
+                revealed: 0,
                 payout: BalanceOf::<T>::default(),
                 closed: false,
             };
 
             <ProposalData<T>>::insert(proposal_hash, proposal);
+            if let Some(call) = call {
+                <ProposalCalls<T>>::insert(proposal_hash, call);
+            }
+            let key = Self::target_key(&target);
+            <TargetNotes<T>>::insert(key, proposal_hash, ());
+            <Candidates<T>>::remove(key);
+            <NoteTargets<T>>::insert(proposal_hash, target.clone());
+            if !bond.is_zero() {
+                <ProposalBonds<T>>::insert(proposal_hash, bond);
+            }
+            Self::schedule_deadline(end, proposal_hash, Phase::Commit)?;
             Self::deposit_event(Event::<T>::Proposed {
-                account: signer,
+                account: signer.clone(),
                 proposal_hash,
             });
+            if !bond.is_zero() {
+                Self::deposit_event(Event::<T>::BondReserved {
+                    proposal_hash,
+                    proposer: signer,
+                    amount: bond,
+                });
+            }
+            Self::deposit_event(Event::<T>::Targeted {
+                proposal_hash,
+                target,
+            });
 
             Ok(())
         }
@@ -416,144 +1374,60 @@ pub mod pallet {
             // check if signer is a member already
             ensure!(Self::is_member(&signer), Error::<T>::NotMember);
 
-            let proposal_data = <ProposalData<T>>::get(&proposal);
-            ensure!(proposal_data.is_some(), Error::<T>::ProposalMissing);
+            Self::do_close_vote(proposal)
+        }
 
-            let mut proposal_data = proposal_data.unwrap();
-            ensure!(
-                proposal_data.reveal_end.is_none(),
-                Error::<T>::VoteAlreadyEnded
-            );
-
-            let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(
-                proposal_data.poll_end <= current_block,
-                Error::<T>::TooEarly
-            );
-
-            let current_block = frame_system::Pallet::<T>::block_number();
-            proposal_data.reveal_end = Some(current_block + T::RevealLength::get());
-
-            <ProposalData<T>>::insert(proposal, proposal_data);
-
-            Self::deposit_event(Event::<T>::ClosedCommit(proposal));
-
-            Ok(())
-        }
-
-        #[pallet::call_index(4)]
-        #[pallet::weight(T::WeightInfo::close_reveal())]
-        pub fn close_reveal(origin: OriginFor<T>, proposal: T::Hash) -> DispatchResult {
-            let signer = ensure_signed(origin)?;
+        /// Closes the reveal phase of a proposal and dispatches its call if it
+        /// is approved. The weight is refunded down to the number of revealed
+        /// votes and the actual weight of the call.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::close_reveal(T::MaxVotersPerProposal::get())
+            .saturating_add(T::WeightInfo::reap_unrevealed(T::ReapLimit::get()))
+            .saturating_add(T::MaxCallWeight::get()))]
+        pub fn close_reveal(origin: OriginFor<T>, proposal: T::Hash) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
 
             //check if signer is a member already | tested
             ensure!(Self::is_member(&signer), Error::<T>::NotMember);
 
-            let proposal_data = <ProposalData<T>>::get(&proposal);
-            ensure!(proposal_data.is_some(), Error::<T>::ProposalMissing);
-
-            let mut proposal_data = proposal_data.unwrap();
-            ensure!(
-                proposal_data.reveal_end.is_some(),
-                Error::<T>::RevealNotStarted
-            );
-
-            // if reveal phase end is not set, that means that we did not start it
-            ensure!(!proposal_data.closed, Error::<T>::RevealEnded);
-
-            let reveal_end = proposal_data.reveal_end.unwrap();
-            let current_block = frame_system::Pallet::<T>::block_number();
-            ensure!(reveal_end <= current_block, Error::<T>::TooEarly);
-
-            // refund voting tokens to voters
-            for (_, (account, votes, _)) in proposal_data.votes.iter().enumerate() {
-                let amount = u8::pow(*votes, 2);
-                Self::deposit_votes(account, amount);
-            }
-
-            //deduce winning side
-            let result = proposal_data.ayes.cmp(&proposal_data.nays);
-            let pot_address = Self::account_id();
-            let amount: BalanceOf<T>;
-            match result {
-                Ordering::Greater => {
-                    let losers: Vec<T::AccountId> = proposal_data
-                        .votes
-                        .iter()
-                        .filter(|entry| entry.2 == Vote::No)
-                        .map(|entry| entry.0.clone())
-                        .collect();
-                    amount = Self::slash_voting_side(losers, &pot_address)?;
-                    let winners: Vec<T::AccountId> = proposal_data
-                        .votes
-                        .iter()
-                        .filter(|entry| entry.2 == Vote::Yes)
-                        .map(|entry| entry.0.clone())
-                        .collect();
-                    Self::reward_voting_side(winners, &pot_address, amount)?;
-                    Self::deposit_event(Event::<T>::Approved(proposal));
-                }
-                Ordering::Less => {
-                    let losers: Vec<T::AccountId> = proposal_data
-                        .votes
-                        .iter()
-                        .filter(|entry| entry.2 == Vote::Yes)
-                        .map(|entry| entry.0.clone())
-                        .collect();
-                    amount = Self::slash_voting_side(losers, &pot_address)?;
-                    let winners: Vec<T::AccountId> = proposal_data
-                        .votes
-                        .iter()
-                        .filter(|entry| entry.2 == Vote::No)
-                        .map(|entry| entry.0.clone())
-                        .collect();
-                    Self::reward_voting_side(winners, &pot_address, amount)?;
-                    Self::deposit_event(Event::<T>::Disapproved(proposal));
-                }
-                Ordering::Equal => {
-                    let losers: Vec<T::AccountId> =
-                        proposal_data.votes.iter().map(|entry| entry.0.clone()).collect();
-                    amount = Self::slash_voting_side(losers, &pot_address)?;
-                    Self::reward_voting_side(
-                        vec![proposal_data.clone().proposer],
-                        &pot_address,
-                        amount,
-                    )?;
-                    Self::deposit_event(Event::<T>::Tie(proposal));
-                }
-            }
-            proposal_data.payout = amount;
-
-            // close proposal
-            proposal_data.closed = true;
-            <ProposalData<T>>::insert(&proposal, proposal_data.clone());
-            Self::deposit_event(Event::<T>::ClosedReveal {
-                proposal_hash: proposal,
-                yes: proposal_data.ayes,
-                no: proposal_data.nays,
-                revealed: proposal_data.revealed.len() as u32,
-                payout: proposal_data.payout,
-            });
-
-            Ok(())
+            let weight = Self::do_close_reveal(proposal)?;
+            Ok(Some(weight).into())
         }
 
+        /// Reveals the vote of a commit. The votes of the members delegating
+        /// to the signer are given along with it.
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::reveal_vote())]
-        pub fn reveal_vote(origin: OriginFor<T>, proposal: T::Hash, vote: Vote) -> DispatchResult {
+        #[pallet::weight(T::WeightInfo::reveal_vote(T::MaxVotersPerProposal::get())
+            .saturating_add(T::WeightInfo::reveal_delegated(T::MaxDelegators::get())))]
+        pub fn reveal_vote(
+            origin: OriginFor<T>,
+            proposal: T::Hash,
+            option: OptionIndex,
+            salt: Salt,
+        ) -> DispatchResultWithPostInfo {
             let signer = ensure_signed(origin)?;
 
             // check if signer is a member already
             ensure!(Self::is_member(&signer), Error::<T>::NotMember);
 
             // verify if the signature exists
-            let commit = <Commits<T>>::take(&signer, &proposal);
+            let commit = <Commits<T>>::get(&signer, proposal);
             ensure!(commit.is_some(), Error::<T>::NoCommit);
             let commit = commit.unwrap();
 
-            let proposal_data = <ProposalData<T>>::get(&proposal);
-            ensure!(proposal_data.is_some(), Error::<T>::ProposalMissing);
-            let mut proposal_data = proposal_data.unwrap();
+            // a commit always belongs to an existing proposal, so missing data means the
+            // proposal has already been closed and archived
+            let proposal_data = <ProposalData<T>>::get(proposal);
+            // votes stay sealed until the commit phase is over
+            if let Some(data) = &proposal_data {
+                ensure!(data.reveal_end.is_some(), Error::<T>::RevealNotStarted);
+            }
+            <Commits<T>>::remove(&signer, proposal);
+            <Committers<T>>::remove(proposal, &signer);
+            let Some(mut proposal_data) = proposal_data else {
+                Self::reveal_late(&signer, proposal, &commit)?;
+                return Ok(().into());
+            };
 
             let reveal_exist = proposal_data.reveal_end;
             if let Some(reveal_end) = reveal_exist {
@@ -562,38 +1436,54 @@ pub mod pallet {
                 // the voter is incentivised to perform this action in order to refund voting
                 // tokens or to cash out
                 if current_block > reveal_end {
-                    let pot_address = Self::account_id();
-                    let _ = Self::slash_voting_side(vec![signer.clone()], &pot_address)?;
-                    let amount = u8::pow(commit.number, 2);
-                    Self::deposit_votes(&signer, amount);
-                    // probably need to refund, but let it be additional punishment
-                    return Ok(());
+                    Self::reveal_late(&signer, proposal, &commit)?;
+                    return Ok(().into());
                 }
             }
 
-            let data = (vote.clone(), commit.salt).encode();
-            let valid_sign = commit.signature.verify(data.as_slice(), &signer);
-            ensure!(valid_sign, Error::<T>::SignatureInvalid);
+            let expected = Self::commitment_of(&option, &salt, &signer, &proposal);
+            ensure!(
+                commit.commitment == expected,
+                Error::<T>::CommitmentMismatch
+            );
 
-            let voted = Self::already_voted(&signer, &proposal_data);
+            let voted = Self::already_voted(&signer, &proposal);
             ensure!(!voted, Error::<T>::DuplicateVote);
 
-            match vote {
-                Vote::Yes => proposal_data.ayes += commit.number as u32,
-                Vote::No => proposal_data.nays += commit.number as u32,
-            }
-
-            proposal_data.votes.push((signer.clone(), commit.number, vote.clone()));
-            proposal_data.revealed.push(signer.clone());
-
-            <ProposalData<T>>::insert(proposal, proposal_data);
-
+            let votes = proposal_data.tally.get_mut(option as usize);
+            ensure!(votes.is_some(), Error::<T>::InvalidOption);
+            let votes = votes.unwrap();
+            *votes = votes.saturating_add(commit.number.saturated_into());
+
+            let revealed = proposal_data.revealed;
+            proposal_data.revealed.saturating_inc();
+            <Votes<T>>::insert(
+                proposal,
+                &signer,
+                VoteRecord {
+                    number: commit.number,
+                    option,
+                },
+            );
             Self::deposit_event(Event::<T>::Voted {
-                account: signer,
+                account: signer.clone(),
                 proposal_hash: proposal,
             });
 
-            Ok(())
+            let delegated = Self::apply_delegations(
+                proposal,
+                &mut proposal_data,
+                &signer,
+                commit.number,
+                option,
+            );
+            <ProposalData<T>>::insert(proposal, proposal_data);
+
+            Ok(Some(
+                T::WeightInfo::reveal_vote(revealed)
+                    .saturating_add(T::WeightInfo::reveal_delegated(delegated)),
+            )
+            .into())
         }
 
         #[pallet::call_index(6)]
@@ -601,45 +1491,50 @@ pub mod pallet {
         pub fn commit_vote(
             origin: OriginFor<T>,
             proposal: T::Hash,
-            data: T::Signature,
-            number: VoteToken,
-            salt: u32,
+            commitment: T::Hash,
+            number: T::VoteCredit,
         ) -> DispatchResult {
             let signer = ensure_signed(origin)?;
             //check if signer is a member already | tested
             ensure!(Self::is_member(&signer), Error::<T>::NotMember);
+            ensure!(
+                !<Suspended<T>>::contains_key(&signer),
+                Error::<T>::MemberSuspended
+            );
 
-            if number == 0 {
+            if number.is_zero() {
                 ensure!(false, Error::<T>::InvalidArgument);
             }
 
             let committed = Self::already_committed_and_exist(&signer, &proposal);
             ensure!(!committed, Error::<T>::DuplicateVote);
 
-            let proposal_data = <ProposalData<T>>::get(&proposal);
+            let proposal_data = <ProposalData<T>>::get(proposal);
             ensure!(proposal_data.is_some(), Error::<T>::ProposalMissing);
-            let proposal_data = proposal_data.unwrap();
+            let mut proposal_data = proposal_data.unwrap();
 
             let current_block = frame_system::Pallet::<T>::block_number();
             ensure!(
                 current_block < proposal_data.poll_end,
                 Error::<T>::VoteEnded
             );
+            ensure!(
+                proposal_data.voters < T::MaxVotersPerProposal::get(),
+                Error::<T>::TooManyVoters
+            );
 
-            let mut tokens_to_take: u8 = number;
-            if number > 1 {
-                tokens_to_take = number.pow(2);
-            }
+            let cost = Self::quadratic_cost(number);
+            ensure!(cost.is_some(), Error::<T>::CostOverflow);
 
-            let enough_tokens = Self::decrease_votes(&signer, tokens_to_take);
+            Self::replenish_member(&signer);
+            let enough_tokens = Self::decrease_votes(&signer, cost.unwrap());
             ensure!(enough_tokens, Error::<T>::NotEnoughVotingTokens);
 
-            let commit = Commit {
-                signature: data,
-                salt,
-                number,
-            };
+            let commit = Commit { commitment, number };
             <Commits<T>>::insert(signer.clone(), proposal, commit);
+            <Committers<T>>::insert(proposal, signer.clone(), ());
+            proposal_data.voters.saturating_inc();
+            <ProposalData<T>>::insert(proposal, proposal_data);
 
             Self::deposit_event(Event::<T>::Committed {
                 account: signer,
@@ -648,22 +1543,781 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Removes the archived outcome of a proposal once its retention period
+        /// is over. Anyone can call it, in case the automatic pruning could not
+        /// be queued.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::prune_result())]
+        pub fn prune_result(origin: OriginFor<T>, proposal: T::Hash) -> DispatchResult {
+            ensure_signed(origin)?;
+            Self::do_prune_result(proposal)
+        }
+
+        /// Removes up to `limit` commits of a closed proposal that were never
+        /// revealed, penalising their voters. Anyone can call it to clean up
+        /// what was left after closing the proposal.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::reap_unrevealed(*limit))]
+        pub fn reap_unrevealed(
+            origin: OriginFor<T>,
+            proposal: T::Hash,
+            limit: u32,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(limit <= T::ReapLimit::get(), Error::<T>::InvalidArgument);
+            ensure!(
+                !<ProposalData<T>>::contains_key(proposal),
+                Error::<T>::NotFinished
+            );
+            ensure!(
+                !<CancelledProposals<T>>::contains_key(proposal),
+                Error::<T>::RefundPending
+            );
+            ensure!(
+                <Committers<T>>::iter_prefix(proposal).next().is_some(),
+                Error::<T>::NoCommit
+            );
+
+            Self::reap_commits(proposal, limit)?;
+            Ok(())
+        }
+
+        /// Redistributes or burns `amount` of the funds held in the pot that
+        /// were not paid out, taken from the dust first. Redistributed funds
+        /// are shared equally between members, the remainder stays as dust.
+        /// Sharing with more than `MaxBeneficiaries` members takes several
+        /// calls.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::spend_pot(T::MaxBeneficiaries::get()))]
+        pub fn spend_pot(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+            spend: PotSpendOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            T::PotOrigin::ensure_origin(origin)?;
+
+            let mut ledger = <Pot<T>>::get();
+            ensure!(amount <= ledger.available(), Error::<T>::InsufficientPot);
+            let from_dust = amount.min(ledger.dust);
+            ledger.dust = ledger.dust.saturating_sub(from_dust);
+            ledger.unclaimed = ledger.unclaimed.saturating_sub(amount.saturating_sub(from_dust));
+
+            let pot_address = Self::account_id();
+            let beneficiaries = match spend {
+                PotSpend::Burn => {
+                    let (_, unburned) = T::Currency::slash_reserved(&pot_address, amount);
+                    let burned = amount.saturating_sub(unburned);
+                    ledger.burned = ledger.burned.saturating_add(burned);
+                    ledger.unclaimed = ledger.unclaimed.saturating_add(unburned);
+                    Self::deposit_event(Event::<T>::PotBurned { amount: burned });
+                    0
+                }
+                PotSpend::Redistribute(beneficiaries) => {
+                    ensure!(!beneficiaries.is_empty(), Error::<T>::NoBeneficiaries);
+                    ensure!(
+                        beneficiaries.iter().all(Self::is_member),
+                        Error::<T>::NotMember
+                    );
+                    let count = beneficiaries.len() as u32;
+                    let voters = beneficiaries.into_iter().map(|who| (who, 1)).collect();
+                    let paid = Self::reward_voting_side(voters, &pot_address, amount)?;
+                    ledger.distributed = ledger.distributed.saturating_add(paid);
+                    ledger.dust = ledger.dust.saturating_add(amount.saturating_sub(paid));
+                    Self::deposit_event(Event::<T>::PotRedistributed {
+                        amount: paid,
+                        beneficiaries: count,
+                    });
+                    count
+                }
+            };
+            <Pot<T>>::put(ledger);
+
+            Ok(Some(T::WeightInfo::spend_pot(beneficiaries)).into())
+        }
+
+        /// Suspends a member whose identity fell below `MinJudgement`, or
+        /// reinstates a suspended member judged well enough again. Anyone can
+        /// call it, members are also checked in `on_idle`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::refresh_judgement())]
+        pub fn refresh_judgement(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(Self::is_member(&member), Error::<T>::NotMember);
+            ensure!(
+                Self::refresh_member(&member),
+                Error::<T>::JudgementUnchanged
+            );
+            Ok(())
+        }
+
+        /// Declares the viewpoint cluster the signing member identifies with.
+        /// It cannot change while the member has votes on open proposals, nor
+        /// within `ViewpointCooldown` blocks of its last change.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::declare_viewpoint(T::MaxProposals::get()))]
+        pub fn declare_viewpoint(origin: OriginFor<T>, viewpoint: Viewpoint) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            if let Some(changed) = <ViewpointChanges<T>>::get(&signer) {
+                let now = <frame_system::Pallet<T>>::block_number();
+                ensure!(
+                    now >= changed.saturating_add(T::ViewpointCooldown::get()),
+                    Error::<T>::ViewpointCooldown
+                );
+            }
+            Self::do_set_viewpoint(&signer, Some(viewpoint))
+        }
+
+        /// Assigns a member to a viewpoint cluster, or clears it. It cannot
+        /// change while the member has votes on open proposals. The cooldown
+        /// does not apply, but restarts for the member.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_viewpoint(T::MaxProposals::get()))]
+        pub fn set_viewpoint(
+            origin: OriginFor<T>,
+            member: T::AccountId,
+            viewpoint: Option<Viewpoint>,
+        ) -> DispatchResult {
+            T::ViewpointOrigin::ensure_origin(origin)?;
+            Self::do_set_viewpoint(&member, viewpoint)
+        }
+
+        /// Imports posts of the feed as candidates for review. Submitted
+        /// unsigned by the offchain worker at most once every
+        /// `ImportInterval` blocks, with the posts signed by an importer; the
+        /// signature is checked when the transaction is validated. Posts
+        /// already annotated or imported are skipped, as well as the ones
+        /// past `MaxCandidates`.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::submit_candidates(payload.candidates.len() as u32))]
+        pub fn submit_candidates(
+            origin: OriginFor<T>,
+            payload: ImportPayloadOf<T>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let next = <NextImportAt<T>>::get();
+            ensure!(now >= next, Error::<T>::ImportTooEarly);
+            ensure!(payload.import_at == next, Error::<T>::StaleImport);
+            ensure!(
+                <Importers<T>>::contains_key(payload.public.into_account()),
+                Error::<T>::NotImporter
+            );
+            <NextImportAt<T>>::put(now.saturating_add(T::ImportInterval::get()));
+
+            for target in payload.candidates {
+                if <Candidates<T>>::count() >= T::MaxCandidates::get() {
+                    break;
+                }
+                if target.platform.is_empty() || !Self::is_new_candidate(&target) {
+                    continue;
+                }
+                let key = Self::target_key(&target);
+                <Candidates<T>>::insert(
+                    key,
+                    Candidate {
+                        target: target.clone(),
+                        imported_at: now,
+                    },
+                );
+                Self::deposit_event(Event::<T>::CandidateImported { key, target });
+            }
+            Ok(())
+        }
+
+        /// Removes a candidate the signing member finds not worth a note.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::dismiss_candidate())]
+        pub fn dismiss_candidate(origin: OriginFor<T>, key: T::Hash) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(Self::is_member(&signer), Error::<T>::NotMember);
+            ensure!(
+                !<Suspended<T>>::contains_key(&signer),
+                Error::<T>::MemberSuspended
+            );
+            ensure!(
+                <Candidates<T>>::contains_key(key),
+                Error::<T>::CandidateMissing
+            );
+
+            <Candidates<T>>::remove(key);
+            Self::deposit_event(Event::<T>::CandidateDismissed {
+                key,
+                account: signer,
+            });
+            Ok(())
+        }
+
+        /// Removes a candidate imported at least `CandidateLifetime` blocks
+        /// ago. Anyone can call it.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::expire_candidate())]
+        pub fn expire_candidate(origin: OriginFor<T>, key: T::Hash) -> DispatchResult {
+            ensure_signed(origin)?;
+            let candidate = <Candidates<T>>::get(key).ok_or(Error::<T>::CandidateMissing)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                now >= candidate.imported_at.saturating_add(T::CandidateLifetime::get()),
+                Error::<T>::CandidateNotExpired
+            );
+
+            <Candidates<T>>::remove(key);
+            Self::deposit_event(Event::<T>::CandidateExpired { key });
+            Ok(())
+        }
+
+        /// Allows an account to sign the imports of the offchain worker, or
+        /// no longer allows it.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_importer())]
+        pub fn set_importer(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            allowed: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if allowed {
+                <Importers<T>>::insert(&account, ());
+            } else {
+                <Importers<T>>::remove(&account);
+            }
+            Self::deposit_event(Event::<T>::ImporterSet { account, allowed });
+            Ok(())
+        }
+
+        /// Refunds up to `limit` more commits and votes of a cancelled
+        /// proposal. Anyone can call it.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::refund_cancelled(*limit))]
+        pub fn refund_cancelled(
+            origin: OriginFor<T>,
+            proposal: T::Hash,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(limit <= T::ReapLimit::get(), Error::<T>::InvalidArgument);
+            ensure!(
+                <CancelledProposals<T>>::contains_key(proposal),
+                Error::<T>::NotCancelled
+            );
+
+            let refunded = Self::refund_voters(proposal, limit);
+            if !Self::has_voters(proposal) {
+                <CancelledProposals<T>>::remove(proposal);
+            }
+            Self::deposit_event(Event::<T>::Refunded {
+                proposal_hash: proposal,
+                refunded,
+            });
+            Ok(Some(T::WeightInfo::refund_cancelled(refunded)).into())
+        }
+
+        /// Delegates the credits of the signing member to another member, for
+        /// the notes about content on a platform or for every note. When the
+        /// delegate reveals a vote, the same votes are given on behalf of the
+        /// signer unless it committed on the proposal itself. A delegation for
+        /// a platform takes precedence over the one for every note, and
+        /// replaces the previous delegation of the same topic.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::delegate(T::MaxDelegationDepth::get()))]
+        pub fn delegate(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            topic: Topic,
+        ) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            ensure!(Self::is_member(&signer), Error::<T>::NotMember);
+            ensure!(
+                !<Suspended<T>>::contains_key(&signer),
+                Error::<T>::MemberSuspended
+            );
+            ensure!(signer != delegate, Error::<T>::SelfDelegation);
+            ensure!(Self::is_member(&delegate), Error::<T>::NotMember);
+            ensure!(
+                topic.as_ref().map_or(true, |platform| !platform.is_empty()),
+                Error::<T>::InvalidTarget
+            );
+            Self::check_delegation_chain(&signer, &delegate, &topic)?;
+
+            let mut delegates = <Delegations<T>>::get(&signer);
+            if let Some(index) = delegates.iter().position(|(t, _)| *t == topic) {
+                let (_, previous) = delegates.remove(index);
+                Self::remove_delegator(&previous, &signer, &topic);
+            }
+            ensure!(
+                delegates.try_push((topic.clone(), delegate.clone())).is_ok(),
+                Error::<T>::TooManyDelegations
+            );
+            <Delegators<T>>::try_mutate(&delegate, |delegators| {
+                delegators.try_push((signer.clone(), topic.clone()))
+            })
+            .map_err(|_| Error::<T>::TooManyDelegators)?;
+            <Delegations<T>>::insert(&signer, delegates);
+
+            Self::deposit_event(Event::<T>::Delegated {
+                delegator: signer,
+                delegate,
+                topic,
+            });
+            Ok(())
+        }
+
+        /// Withdraws the delegation of the signing member for a topic.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::undelegate())]
+        pub fn undelegate(origin: OriginFor<T>, topic: Topic) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+
+            let mut delegates = <Delegations<T>>::get(&signer);
+            let index = delegates.iter().position(|(t, _)| *t == topic);
+            ensure!(index.is_some(), Error::<T>::NotDelegated);
+            let (_, delegate) = delegates.remove(index.unwrap());
+            Self::remove_delegator(&delegate, &signer, &topic);
+            if delegates.is_empty() {
+                <Delegations<T>>::remove(&signer);
+            } else {
+                <Delegations<T>>::insert(&signer, delegates);
+            }
+
+            Self::deposit_event(Event::<T>::Undelegated {
+                delegator: signer,
+                topic,
+            });
+            Ok(())
+        }
+
+        /// Replenishes the credits of a member that were not replenished yet
+        /// in the current epoch. Anyone can call it, members are also
+        /// replenished in `on_idle` and when they commit.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::replenish_credits())]
+        pub fn replenish_credits(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(Self::is_member(&member), Error::<T>::NotMember);
+            ensure!(Self::replenish_member(&member), Error::<T>::CreditsUpToDate);
+            Ok(())
+        }
+
+        /// Withdraws a proposal of the signer as long as nobody committed on
+        /// it, e.g. to fix a typo. Its bond is given back.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::withdraw_proposal(T::MaxProposals::get()))]
+        pub fn withdraw_proposal(origin: OriginFor<T>, proposal: T::Hash) -> DispatchResult {
+            let signer = ensure_signed(origin)?;
+            let proposal_data =
+                <ProposalData<T>>::get(proposal).ok_or(Error::<T>::ProposalMissing)?;
+            ensure!(proposal_data.proposer == signer, Error::<T>::NotProposer);
+            ensure!(proposal_data.voters == 0, Error::<T>::ProposalHasCommits);
+
+            Self::remove_proposal(proposal, &proposal_data);
+            Self::deposit_event(Event::<T>::Withdrawn {
+                proposal_hash: proposal,
+                proposer: signer,
+            });
+            Ok(())
+        }
+
+        /// Cancels a proposal in its commit or reveal phase, e.g. an abusive
+        /// note. The credits of its commits and votes are given back in full,
+        /// whatever the `CreditPolicy`, and so is the bond of its proposer.
+        /// Up to `ReapLimit` commits and votes are refunded, the rest through
+        /// `refund_cancelled`.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::cancel_proposal(T::ReapLimit::get()))]
+        pub fn cancel_proposal(
+            origin: OriginFor<T>,
+            proposal: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            T::CancelOrigin::ensure_origin(origin)?;
+            let proposal_data =
+                <ProposalData<T>>::get(proposal).ok_or(Error::<T>::ProposalMissing)?;
+
+            let refunded = Self::refund_voters(proposal, T::ReapLimit::get());
+            Self::remove_proposal(proposal, &proposal_data);
+            if Self::has_voters(proposal) {
+                <CancelledProposals<T>>::insert(proposal, ());
+            }
+            Self::deposit_event(Event::<T>::Cancelled {
+                proposal_hash: proposal,
+                refunded,
+            });
+            Ok(Some(T::WeightInfo::cancel_proposal(refunded)).into())
+        }
+
+        /// Removes a member, e.g. a malicious one. Its pending commits and the
+        /// votes it revealed on active proposals are dropped from their tally,
+        /// then it leaves the committee as with `leave_committee`.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::force_remove_member(
+            T::MaxProposals::get(),
+            T::MaxDelegators::get().saturating_add(types::MAX_DELEGATIONS)
+        ))]
+        pub fn force_remove_member(
+            origin: OriginFor<T>,
+            member: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Self::is_member(&member), Error::<T>::NotMember);
+
+            let dropped = Self::drop_votes(&member);
+            let (cashout, delegations) = Self::remove_member(&member);
+            Self::deposit_event(Event::<T>::MemberRemoved {
+                account: member,
+                cashout,
+            });
+            Ok(Some(T::WeightInfo::force_remove_member(dropped, delegations)).into())
+        }
+
+        /// Ends the commit phase of a proposal now, whatever its deadline
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::force_close_vote())]
+        pub fn force_close_vote(origin: OriginFor<T>, proposal: T::Hash) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let proposal_data =
+                <ProposalData<T>>::get(proposal).ok_or(Error::<T>::ProposalMissing)?;
+            ensure!(
+                proposal_data.reveal_end.is_none(),
+                Error::<T>::VoteAlreadyEnded
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+            Self::unschedule_deadline(proposal_data.poll_end, proposal);
+            <ProposalData<T>>::mutate(proposal, |data| {
+                if let Some(data) = data {
+                    data.poll_end = now;
+                }
+            });
+            Self::do_close_vote(proposal)?;
+            Self::deposit_event(Event::<T>::ForceClosed {
+                proposal_hash: proposal,
+                phase: Phase::Commit,
+            });
+            Ok(())
+        }
+
+        /// Ends the reveal phase of a proposal now, whatever its deadline. The
+        /// weight is refunded as with `close_reveal`.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::force_close_reveal(T::MaxVotersPerProposal::get())
+            .saturating_add(T::WeightInfo::reap_unrevealed(T::ReapLimit::get()))
+            .saturating_add(T::MaxCallWeight::get()))]
+        pub fn force_close_reveal(
+            origin: OriginFor<T>,
+            proposal: T::Hash,
+        ) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let proposal_data =
+                <ProposalData<T>>::get(proposal).ok_or(Error::<T>::ProposalMissing)?;
+            let Some(reveal_end) = proposal_data.reveal_end else {
+                return Err(Error::<T>::RevealNotStarted.into());
+            };
+            ensure!(!proposal_data.closed, Error::<T>::RevealEnded);
+
+            let now = frame_system::Pallet::<T>::block_number();
+            Self::unschedule_deadline(reveal_end, proposal);
+            <ProposalData<T>>::mutate(proposal, |data| {
+                if let Some(data) = data {
+                    data.reveal_end = Some(now);
+                }
+            });
+            let weight = Self::do_close_reveal(proposal)?;
+            Self::deposit_event(Event::<T>::ForceClosed {
+                proposal_hash: proposal,
+                phase: Phase::Reveal,
+            });
+            Ok(Some(weight).into())
+        }
+
+        /// Overwrites the credits and the reserved funds of a member, e.g. to
+        /// correct a corrupt balance. The difference with the funds recorded
+        /// before is reserved or unreserved, so that the record matches the
+        /// funds the member gets back on leaving.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::force_set_member_balance())]
+        pub fn force_set_member_balance(
+            origin: OriginFor<T>,
+            member: T::AccountId,
+            balance: VoterBalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Self::is_member(&member), Error::<T>::NotMember);
+            ensure!(
+                balance.voting_tokens <= Self::max_voting_tokens(),
+                Error::<T>::InvalidArgument
+            );
+
+            let recorded = <Members<T>>::get(&member).reserved_balance;
+            if balance.reserved_balance > recorded {
+                T::Currency::reserve(&member, balance.reserved_balance - recorded)
+                    .map_err(|_| Error::<T>::NotEnoughFunds)?;
+            } else {
+                // only the funds of the member itself, not its bonds, are given back
+                let released = recorded - balance.reserved_balance;
+                let own =
+                    T::Currency::reserved_balance(&member).saturating_sub(Self::bonds_of(&member));
+                ensure!(own >= released, Error::<T>::ReserveShortfall);
+                let missing = T::Currency::unreserve(&member, released);
+                ensure!(missing.is_zero(), Error::<T>::ReserveShortfall);
+            }
+            <Members<T>>::insert(&member, balance.clone());
+            Self::deposit_event(Event::<T>::MemberBalanceSet {
+                account: member,
+                balance,
+            });
+            Ok(())
+        }
+
+        /// Sets a voting parameter in place of the runtime configuration, or
+        /// restores it. It applies from the next call reading it: proposals
+        /// and members keep the lengths and deposit they started with.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_parameter())]
+        pub fn set_parameter(
+            origin: OriginFor<T>,
+            parameter: VotingParameterOf<T>,
+        ) -> DispatchResult {
+            T::ParameterOrigin::ensure_origin(origin)?;
+            let valid = match parameter {
+                VotingParameter::EntryFee(_) => true,
+                VotingParameter::RevealLength(length) | VotingParameter::MinLength(length) => {
+                    length.map_or(true, |length| !length.is_zero())
+                }
+                VotingParameter::MaxVotingTokens(credits) => credits.map_or(true, |credits| {
+                    !credits.is_zero() && credits <= T::MaxVotingTokens::get()
+                }),
+                VotingParameter::MaxProposals(max) => {
+                    max.map_or(true, |max| max > 0 && max <= T::MaxProposals::get())
+                }
+            };
+            ensure!(valid, Error::<T>::InvalidParameter);
+
+            <Parameters<T>>::mutate(|parameters| match parameter {
+                VotingParameter::EntryFee(fee) => parameters.entry_fee = fee,
+                VotingParameter::RevealLength(length) => parameters.reveal_length = length,
+                VotingParameter::MinLength(length) => parameters.min_length = length,
+                VotingParameter::MaxVotingTokens(credits) => parameters.max_voting_tokens = credits,
+                VotingParameter::MaxProposals(max) => parameters.max_proposals = max,
+            });
+            Self::deposit_event(Event::<T>::ParameterSet { parameter });
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
     pub fn is_member(account: &T::AccountId) -> bool { Members::<T>::contains_key(account) }
 
+    /// Track record of an account
+    pub fn reputation(who: &T::AccountId) -> ReputationOf<T> { <Reputations<T>>::get(who) }
+
+    /// Publishes the current track record of `who` in
+    /// [`Event::ReputationUpdated`]
+    pub fn report_reputation(who: &T::AccountId) {
+        Self::deposit_event(Event::<T>::ReputationUpdated {
+            account: who.clone(),
+            reputation: <Reputations<T>>::get(who),
+        });
+    }
+
+    /// Whether the identity of `account` is judged at least `MinJudgement`
+    pub fn is_judged(account: &T::AccountId) -> bool {
+        T::IdentityProvider::judgement(account).is_some_and(|j| j >= T::MinJudgement::get())
+    }
+
+    /// Suspends `member` if its identity is no longer judged well enough, or
+    /// reinstates it if it is again. Returns whether anything changed.
+    pub fn refresh_member(member: &T::AccountId) -> bool {
+        let judged = Self::is_judged(member);
+        let suspended = <Suspended<T>>::contains_key(member);
+        if !judged && !suspended {
+            <Suspended<T>>::insert(member, frame_system::Pallet::<T>::block_number());
+            Self::deposit_event(Event::<T>::MemberSuspended {
+                account: member.clone(),
+            });
+        } else if judged && suspended {
+            <Suspended<T>>::remove(member);
+            Self::deposit_event(Event::<T>::MemberReinstated {
+                account: member.clone(),
+            });
+        }
+        judged == suspended
+    }
+
+    /// Refreshes the suspension of as many members as fit in `limit`,
+    /// resuming after the member checked last. Returns the weight consumed.
+    pub fn check_judgements(limit: Weight) -> Weight {
+        let per_member = T::WeightInfo::refresh_judgement();
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        if weight.saturating_add(per_member).any_gt(limit) {
+            return Weight::zero();
+        }
+
+        let mut members = match <JudgementCursor<T>>::get() {
+            Some(last) => <Members<T>>::iter_from(<Members<T>>::hashed_key_for(last)),
+            None => <Members<T>>::iter(),
+        };
+        let mut last = None;
+        while weight.saturating_add(per_member).all_lte(limit) {
+            let Some((member, _)) = members.next() else {
+                last = None;
+                break;
+            };
+            Self::refresh_member(&member);
+            weight.saturating_accrue(per_member);
+            last = Some(member);
+        }
+        match last {
+            Some(last) => <JudgementCursor<T>>::put(last),
+            None => <JudgementCursor<T>>::kill(),
+        }
+        weight
+    }
+
+    /// The epoch `now` falls in, always the first one unless credits are
+    /// replenished in epochs
+    pub fn epoch_at(now: BlockNumberFor<T>) -> Epoch {
+        match T::CreditPolicy::get() {
+            CreditPolicy::Epochs { length, .. } if !length.is_zero() => {
+                (now / length).saturated_into()
+            }
+            _ => 0,
+        }
+    }
+
+    /// The epoch of the current block
+    pub fn current_epoch() -> Epoch { Self::epoch_at(frame_system::Pallet::<T>::block_number()) }
+
+    /// Announces the epoch starting at `now`, if one does
+    pub fn start_epoch(now: BlockNumberFor<T>) {
+        if let CreditPolicy::Epochs { length, .. } = T::CreditPolicy::get() {
+            if !now.is_zero() && !length.is_zero() && (now % length).is_zero() {
+                Self::deposit_event(Event::<T>::EpochStarted {
+                    epoch: Self::epoch_at(now),
+                });
+            }
+        }
+    }
+
+    /// Replenishes the credits of `member` according to `CreditPolicy` if
+    /// they were last replenished in an earlier epoch. Returns whether they
+    /// were.
+    pub fn replenish_member(member: &T::AccountId) -> bool {
+        let CreditPolicy::Epochs {
+            grant, carry_over, ..
+        } = T::CreditPolicy::get()
+        else {
+            return false;
+        };
+        let epoch = Self::current_epoch();
+        if !Self::is_member(member) || <CreditEpochs<T>>::get(member) >= epoch {
+            return false;
+        }
+
+        <CreditEpochs<T>>::insert(member, epoch);
+        let credits = <Members<T>>::mutate(member, |balance| {
+            let kept =
+                carry_over.map_or(balance.voting_tokens, |cap| balance.voting_tokens.min(cap));
+            balance.voting_tokens = kept.saturating_add(grant).min(Self::max_voting_tokens());
+            balance.voting_tokens
+        });
+        Self::deposit_event(Event::<T>::CreditsReplenished {
+            account: member.clone(),
+            epoch,
+            credits,
+        });
+        true
+    }
+
+    /// Replenishes the credits of as many members as fit in `limit`, until
+    /// every member is replenished in the current epoch. Returns the weight
+    /// consumed.
+    pub fn replenish_members(limit: Weight) -> Weight {
+        if T::CreditPolicy::get() == CreditPolicy::RefundOnClose {
+            return Weight::zero();
+        }
+        let per_member = T::WeightInfo::replenish_credits();
+        let mut weight = T::DbWeight::get().reads_writes(2, 2);
+        if weight.saturating_add(per_member).any_gt(limit) {
+            return Weight::zero();
+        }
+        let epoch = Self::current_epoch();
+        if <ReplenishedEpoch<T>>::get() >= epoch {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut members = match <ReplenishCursor<T>>::get() {
+            Some(last) => <Members<T>>::iter_from(<Members<T>>::hashed_key_for(last)),
+            None => <Members<T>>::iter(),
+        };
+        let mut last = None;
+        while weight.saturating_add(per_member).all_lte(limit) {
+            let Some((member, _)) = members.next() else {
+                last = None;
+                break;
+            };
+            Self::replenish_member(&member);
+            weight.saturating_accrue(per_member);
+            last = Some(member);
+        }
+        match last {
+            Some(last) => <ReplenishCursor<T>>::put(last),
+            None => {
+                <ReplenishedEpoch<T>>::put(epoch);
+                <ReplenishCursor<T>>::kill();
+            }
+        }
+        weight
+    }
+
+    /// Sets the viewpoint cluster of `member`, refusing while it has a commit
+    /// or a revealed vote on an open proposal so that outcomes cannot be
+    /// swayed by switching clusters.
+    pub fn do_set_viewpoint(member: &T::AccountId, viewpoint: Option<Viewpoint>) -> DispatchResult {
+        ensure!(Self::is_member(member), Error::<T>::NotMember);
+        ensure!(
+            viewpoint.map_or(true, |viewpoint| viewpoint < types::MAX_VIEWPOINTS),
+            Error::<T>::InvalidViewpoint
+        );
+        ensure!(!Self::has_open_votes(member), Error::<T>::InMotion);
+
+        <Viewpoints<T>>::set(member, viewpoint);
+        <ViewpointChanges<T>>::insert(member, <frame_system::Pallet<T>>::block_number());
+        Self::deposit_event(Event::<T>::ViewpointSet {
+            account: member.clone(),
+            viewpoint,
+        });
+        Ok(())
+    }
+
+    /// Whether `who` has a commit or a revealed vote on an open proposal
+    pub fn has_open_votes(who: &T::AccountId) -> bool {
+        <Commits<T>>::iter_prefix_values(who).next().is_some()
+            || <Proposals<T>>::get()
+                .iter()
+                .any(|proposal| <Votes<T>>::contains_key(proposal, who))
+    }
+
     pub fn proposal_exist(proposal: &T::Hash) -> (bool, BoundedVec<T::Hash, T::MaxProposals>) {
         let proposals = <Proposals<T>>::get();
         (proposals.contains(proposal), proposals)
     }
 
-    pub fn already_voted(
+    pub fn already_voted(who: &T::AccountId, proposal_hash: &T::Hash) -> bool {
+        <Votes<T>>::contains_key(proposal_hash, who)
+    }
+
+    /// Computes the blinded commitment of a vote for a rating option. The
+    /// voter and the proposal are part of the preimage so a commitment cannot
+    /// be replayed by another account or on another proposal.
+    pub fn commitment_of(
+        option: &types::OptionIndex,
+        salt: &types::Salt,
         who: &T::AccountId,
-        proposal: &types::Proposal<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
-    ) -> bool {
-        proposal.revealed.contains(who)
+        proposal_hash: &T::Hash,
+    ) -> T::Hash {
+        T::Hashing::hash_of(&(option, salt, who, proposal_hash))
     }
 
     pub fn already_committed_and_exist(who: &T::AccountId, proposal_hash: &T::Hash) -> bool {
@@ -672,25 +2326,64 @@ impl<T: Config> Pallet<T> {
 
     /// Deposit voting tokens to the account and make sure it does not exceed
     /// the limit
-    pub fn deposit_votes(who: &T::AccountId, tokens: u8) {
-        <Members<T>>::mutate(who, |balance| {
-            balance.voting_tokens += tokens;
-            if balance.voting_tokens > T::MaxVotingTokens::get() {
-                balance.voting_tokens = T::MaxVotingTokens::get();
+    pub fn deposit_votes(who: &T::AccountId, tokens: T::VoteCredit) {
+        <Members<T>>::mutate_exists(who, |balance| {
+            if let Some(balance) = balance {
+                balance.voting_tokens =
+                    balance.voting_tokens.saturating_add(tokens).min(Self::max_voting_tokens());
             }
         });
     }
 
+    /// The credits `number` votes cost, `None` if it overflows
+    pub fn quadratic_cost(number: T::VoteCredit) -> Option<T::VoteCredit> {
+        number.checked_mul(&number)
+    }
+
+    /// Gives back the credits spent on `number` votes. Their cost was checked
+    /// on commit, and a deposit never exceeds `MaxVotingTokens` anyway.
+    /// Nothing is given back when credits are replenished in epochs.
+    pub fn refund_votes(who: &T::AccountId, number: T::VoteCredit) {
+        if T::CreditPolicy::get() == CreditPolicy::RefundOnClose {
+            Self::return_votes(who, number);
+        }
+    }
+
+    /// Gives back the credits spent on `number` votes whatever the
+    /// `CreditPolicy`, for votes on a proposal that was cancelled
+    pub fn return_votes(who: &T::AccountId, number: T::VoteCredit) {
+        let cost = Self::quadratic_cost(number).unwrap_or_else(T::MaxVotingTokens::get);
+        Self::deposit_votes(who, cost);
+    }
+
     /// Update the internal record of funds reserved under the account
     pub fn set_reserved_balance(who: &T::AccountId, funds: BalanceOf<T>) {
-        <Members<T>>::mutate(who, |balance| {
-            balance.reserved_balance = funds;
+        <Members<T>>::mutate_exists(who, |balance| {
+            if let Some(balance) = balance {
+                balance.reserved_balance = funds;
+            }
+        });
+    }
+
+    /// Decreases the internal record of funds reserved under the account
+    pub fn reduce_reserved_balance(who: &T::AccountId, funds: BalanceOf<T>) {
+        <Members<T>>::mutate_exists(who, |balance| {
+            if let Some(balance) = balance {
+                balance.reserved_balance = balance.reserved_balance.saturating_sub(funds);
+            }
         });
     }
 
+    /// Whether `who` holds a revealed or delegated vote on an active proposal
+    pub fn has_votes(who: &T::AccountId) -> bool {
+        <Proposals<T>>::get()
+            .iter()
+            .any(|proposal| <Votes<T>>::contains_key(proposal, who))
+    }
+
     /// tries to decrease the voting tokens of a specific account by specified
     /// amount. Returns false if account does not have enough voting tokens
-    pub fn decrease_votes(who: &T::AccountId, amount: u8) -> bool {
+    pub fn decrease_votes(who: &T::AccountId, amount: T::VoteCredit) -> bool {
         <Members<T>>::try_mutate(who, |balance| {
             if balance.voting_tokens < amount {
                 return Err(());
@@ -701,19 +2394,15 @@ impl<T: Config> Pallet<T> {
         .is_ok()
     }
 
-    /// Slashes the losing side, puts money in a pot and returns the total
-    /// amount slashed
+    /// Slashes the losing side according to `SlashPolicy`, puts money in a
+    /// pot and returns the total amount slashed
     pub fn slash_voting_side(
-        voters: Vec<T::AccountId>,
+        voters: Vec<(T::AccountId, u32)>,
         pot: &T::AccountId,
     ) -> Result<BalanceOf<T>, DispatchError> {
         let mut payout: BalanceOf<T> = BalanceOf::<T>::default();
-        for voter in voters {
-            let denominator: BalanceOf<T> = 10u8.into();
-            let slash = T::Currency::reserved_balance(&voter)
-                .checked_div(&denominator.clone())
-                .get_or_insert(BalanceOf::<T>::default())
-                .to_owned();
+        for (voter, number) in voters {
+            let slash = Self::side_slash(&voter, number);
             let lost = T::Currency::repatriate_reserved(
                 &voter,
                 pot,
@@ -723,23 +2412,57 @@ impl<T: Config> Pallet<T> {
 
             // calculate how much funds have actually been slashed
             let slashed = slash.saturating_sub(lost);
-            <Members<T>>::mutate(&voter, |balance| {
-                balance.reserved_balance = balance.reserved_balance.saturating_sub(slashed);
+            Self::reduce_reserved_balance(&voter, slashed);
+            <Reputations<T>>::mutate(&voter, |reputation| {
+                reputation.slashed = reputation.slashed.saturating_add(slashed);
             });
             // even though we may not necessary
             payout = payout.saturating_add(slashed);
         }
         Ok(payout)
     }
-    /// Rewards evenly every member from the pot with the provided sum
+
+    /// The part of the reserved funds a voter on the losing side who gave
+    /// `number` votes is slashed
+    pub fn side_slash(voter: &T::AccountId, number: u32) -> BalanceOf<T> {
+        T::SlashPolicy::slash(Self::stake(voter), number)
+    }
+
+    /// The funds a member has at stake: its deposit with its rewards and
+    /// slashes, but not the bonds of its proposals
+    pub fn stake(who: &T::AccountId) -> BalanceOf<T> {
+        <Members<T>>::get(who).reserved_balance.min(T::Currency::reserved_balance(who))
+    }
+
+    /// The part of `total` paid to a voter who gave `number` votes out of the
+    /// `weight` votes of the rewarded side, rounded down
+    pub fn reward_share(total: BalanceOf<T>, number: u32, weight: u32) -> BalanceOf<T> {
+        if weight == 0 {
+            return BalanceOf::<T>::default();
+        }
+        let number: BalanceOf<T> = number.into();
+        let weight: BalanceOf<T> = weight.into();
+        (total / weight)
+            .saturating_mul(number)
+            .saturating_add((total % weight).saturating_mul(number) / weight)
+    }
+
+    /// Rewards the winning side from the pot with the provided sum, pro rata
+    /// to the votes each voter gave, and returns the amount actually paid.
+    /// Nothing is paid when no voter is given.
     pub fn reward_voting_side(
-        voters: Vec<T::AccountId>,
+        voters: Vec<(T::AccountId, u32)>,
         pot: &T::AccountId,
         total: BalanceOf<T>,
-    ) -> Result<(), DispatchError> {
-        let len = voters.len() as u32;
-        let share = total / len.into();
-        for voter in voters {
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let weight = voters.iter().fold(0u32, |weight, (_, number)| weight.saturating_add(*number));
+        let mut paid = BalanceOf::<T>::default();
+        for (voter, number) in voters {
+            // the share of an account that is no longer a member stays in the pot
+            if !Self::is_member(&voter) {
+                continue;
+            }
+            let share = Self::reward_share(total, number, weight);
             let lost = T::Currency::repatriate_reserved(
                 pot,
                 &voter,
@@ -748,13 +2471,948 @@ impl<T: Config> Pallet<T> {
             )?;
             let actual_share = share.saturating_sub(lost);
             // increase the reserved funds under the account
-            <Members<T>>::mutate(&voter, |balance| {
-                balance.reserved_balance = balance.reserved_balance.saturating_add(actual_share);
+            <Members<T>>::mutate_exists(&voter, |balance| {
+                if let Some(balance) = balance {
+                    balance.reserved_balance =
+                        balance.reserved_balance.saturating_add(actual_share);
+                }
+            });
+            <Reputations<T>>::mutate(&voter, |reputation| {
+                reputation.rewarded = reputation.rewarded.saturating_add(actual_share);
+            });
+            paid = paid.saturating_add(actual_share);
+        }
+        Ok(paid)
+    }
+
+    /// Records `slashed` funds that entered the pot for a proposal, of which
+    /// `paid` were rewarded right away. The rest is kept as dust when a side
+    /// was rewarded, and as unclaimed funds otherwise.
+    pub fn record_pot(
+        proposal: T::Hash,
+        slashed: BalanceOf<T>,
+        paid: BalanceOf<T>,
+        rewarded: bool,
+    ) {
+        if slashed.is_zero() {
+            return;
+        }
+        if <ProposalData<T>>::contains_key(proposal) || <ProposalResults<T>>::contains_key(proposal)
+        {
+            <PotInflows<T>>::mutate(proposal, |inflow| *inflow = inflow.saturating_add(slashed));
+        }
+        <Pot<T>>::mutate(|ledger| {
+            let rest = slashed.saturating_sub(paid);
+            ledger.inflow = ledger.inflow.saturating_add(slashed);
+            ledger.distributed = ledger.distributed.saturating_add(paid);
+            if rewarded {
+                ledger.dust = ledger.dust.saturating_add(rest);
+            } else {
+                ledger.unclaimed = ledger.unclaimed.saturating_add(rest);
+            }
+        });
+    }
+
+    /// Ends the commit phase of a proposal and starts its reveal phase
+    pub fn do_close_vote(proposal: T::Hash) -> DispatchResult {
+        let proposal_data = <ProposalData<T>>::get(proposal);
+        ensure!(proposal_data.is_some(), Error::<T>::ProposalMissing);
+
+        let mut proposal_data = proposal_data.unwrap();
+        ensure!(
+            proposal_data.reveal_end.is_none(),
+            Error::<T>::VoteAlreadyEnded
+        );
+
+        let current_block = frame_system::Pallet::<T>::block_number();
+        ensure!(
+            proposal_data.poll_end <= current_block,
+            Error::<T>::TooEarly
+        );
+
+        let reveal_end = current_block + Self::reveal_length();
+        Self::schedule_deadline(reveal_end, proposal, Phase::Reveal)?;
+        proposal_data.reveal_end = Some(reveal_end);
+
+        <ProposalData<T>>::insert(proposal, proposal_data);
+
+        Self::deposit_event(Event::<T>::ClosedCommit(proposal));
+
+        Ok(())
+    }
+
+    /// Ends the reveal phase of a proposal, refunds voting tokens, slashes the
+    /// losing side and rewards the winning one. Returns the weight consumed,
+    /// which depends on the number of revealed votes.
+    pub fn do_close_reveal(proposal: T::Hash) -> Result<Weight, DispatchError> {
+        let proposal_data = <ProposalData<T>>::get(proposal);
+        ensure!(proposal_data.is_some(), Error::<T>::ProposalMissing);
+
+        let mut proposal_data = proposal_data.unwrap();
+        ensure!(
+            proposal_data.reveal_end.is_some(),
+            Error::<T>::RevealNotStarted
+        );
+
+        // if reveal phase end is not set, that means that we did not start it
+        ensure!(!proposal_data.closed, Error::<T>::RevealEnded);
+
+        let reveal_end = proposal_data.reveal_end.unwrap();
+        let current_block = frame_system::Pallet::<T>::block_number();
+        ensure!(reveal_end <= current_block, Error::<T>::TooEarly);
+        ensure!(
+            Self::can_schedule(current_block + T::ResultRetention::get()),
+            Error::<T>::TooManyDeadlines
+        );
+
+        // refund voting tokens to voters
+        let votes: Vec<(T::AccountId, VoteRecordOf<T>)> =
+            <Votes<T>>::drain_prefix(proposal).collect();
+        for (account, vote) in votes.iter() {
+            Self::refund_votes(account, vote.number);
+        }
+
+        // deduce the winning option, a tie if several options share the top
+        let winners = T::OutcomeRule::winners(&proposal_data.tally, &votes);
+        let outcome = T::OutcomeRule::outcome(&winners);
+        let pot_address = Self::account_id();
+        let (losers, rewarded) = Self::sides(&votes, &winners);
+        for (account, vote) in votes.iter() {
+            <Reputations<T>>::mutate(account, |reputation| {
+                reputation.participated.saturating_inc();
+                if winners[..] == [vote.option] {
+                    reputation.aligned.saturating_inc();
+                }
+            });
+        }
+        let amount = Self::slash_voting_side(losers, &pot_address)?;
+        let has_rewarded = !rewarded.is_empty();
+        // voters and the rewarded proposer, each reported once
+        let mut reported: Vec<T::AccountId> = votes
+            .iter()
+            .map(|(account, _)| account.clone())
+            .chain(rewarded.iter().map(|(account, _)| account.clone()))
+            .collect();
+        let paid = Self::reward_voting_side(rewarded, &pot_address, amount)?;
+        Self::record_pot(proposal, amount, paid, has_rewarded);
+        Self::settle_bond(proposal, &proposal_data.proposer, outcome, &pot_address)?;
+        match outcome {
+            Outcome::Approved => Self::deposit_event(Event::<T>::Approved(proposal)),
+            Outcome::Disapproved => Self::deposit_event(Event::<T>::Disapproved(proposal)),
+            Outcome::Tie => Self::deposit_event(Event::<T>::Tie(proposal)),
+        }
+        proposal_data.payout = amount;
+
+        // close proposal and move it out of the active set
+        proposal_data.closed = true;
+        Self::archive_proposal(proposal, &proposal_data, outcome, winners.clone())?;
+        Self::deposit_event(Event::<T>::ClosedReveal {
+            proposal_hash: proposal,
+            breakdown: types::breakdown(&proposal_data.tally),
+            tally: proposal_data.tally,
+            winners,
+            revealed: proposal_data.revealed,
+            payout: proposal_data.payout,
+        });
+        reported.sort();
+        reported.dedup();
+        for account in reported.iter() {
+            Self::report_reputation(account);
+        }
+
+        let reaped = Self::reap_commits(proposal, T::ReapLimit::get())?;
+        let mut weight = T::WeightInfo::close_reveal(votes.len() as u32)
+            .saturating_add(T::WeightInfo::reap_unrevealed(reaped));
+
+        // the call is dispatched by whoever closes the proposal, which budgets
+        // `MaxCallWeight` for it and is charged its actual weight
+        let call = <ProposalCalls<T>>::take(proposal);
+        if let (Some(call), Outcome::Approved) = (call, outcome) {
+            weight.saturating_accrue(Self::dispatch_call(proposal, &call));
+        }
+
+        Ok(weight)
+    }
+
+    /// Splits the revealed votes into the voters slashed and the ones
+    /// rewarded, with the number of votes each gave. With a single winning
+    /// option its voters are rewarded. On a tie every voter is slashed and
+    /// nobody is rewarded, so that a deadlock pays nobody. Without a winner
+    /// nobody is slashed.
+    #[allow(clippy::type_complexity)]
+    pub fn sides(
+        votes: &[(T::AccountId, VoteRecordOf<T>)],
+        winners: &[types::OptionIndex],
+    ) -> (Vec<(T::AccountId, u32)>, Vec<(T::AccountId, u32)>) {
+        let stake = |(account, vote): &(T::AccountId, VoteRecordOf<T>)| {
+            (account.clone(), vote.number.saturated_into())
+        };
+        match winners {
+            [winner] => (
+                votes.iter().filter(|(_, vote)| vote.option != *winner).map(stake).collect(),
+                votes.iter().filter(|(_, vote)| vote.option == *winner).map(stake).collect(),
+            ),
+            [] => (Vec::new(), Vec::new()),
+            _ => (votes.iter().map(stake).collect(), Vec::new()),
+        }
+    }
+
+    /// Settles the bond of a closed proposal: `BondSlash` of it is slashed
+    /// into the pot, where it is kept unclaimed, if the note was disapproved,
+    /// and the rest is given back to the proposer.
+    pub fn settle_bond(
+        proposal: T::Hash,
+        proposer: &T::AccountId,
+        outcome: Outcome,
+        pot: &T::AccountId,
+    ) -> DispatchResult {
+        let Some(bond) = <ProposalBonds<T>>::take(proposal) else {
+            return Ok(());
+        };
+        let slash = match outcome {
+            Outcome::Disapproved => T::BondSlash::get().mul_floor(bond),
+            Outcome::Approved | Outcome::Tie => BalanceOf::<T>::default(),
+        };
+        if !slash.is_zero() {
+            let lost = T::Currency::repatriate_reserved(
+                proposer,
+                pot,
+                slash,
+                frame_support::traits::BalanceStatus::Reserved,
+            )?;
+            let slashed = slash.saturating_sub(lost);
+            Self::record_pot(proposal, slashed, BalanceOf::<T>::default(), false);
+            Self::deposit_event(Event::<T>::BondSlashed {
+                proposal_hash: proposal,
+                proposer: proposer.clone(),
+                amount: slashed,
+            });
+        }
+        let rest = bond.saturating_sub(slash);
+        let returned = rest.saturating_sub(T::Currency::unreserve(proposer, rest));
+        Self::deposit_event(Event::<T>::BondReturned {
+            proposal_hash: proposal,
+            proposer: proposer.clone(),
+            amount: returned,
+        });
+        Ok(())
+    }
+
+    /// Dispatches the call of an approved proposal with the
+    /// [`RawOrigin::Approved`] origin. A failure does not revert the closing
+    /// of the proposal, it is only reported in [`Event::CallFailed`]. Returns
+    /// the weight of the call.
+    pub fn dispatch_call(proposal: T::Hash, encoded: &[u8]) -> Weight {
+        let mut weight = Weight::zero();
+        let result = <T as Config>::RuntimeCall::decode(&mut &encoded[..])
+            .map_err(|_| Error::<T>::UndecodableCall.into())
+            .and_then(|call| {
+                let info = call.get_dispatch_info();
+                ensure!(
+                    info.weight.all_lte(T::MaxCallWeight::get()),
+                    Error::<T>::CallTooHeavy
+                );
+                weight = info.weight;
+                call.dispatch(RawOrigin::Approved(proposal).into())
+                    .map(|post| weight = post.calc_actual_weight(&info))
+                    .map_err(|e| {
+                        weight = e.post_info.calc_actual_weight(&info);
+                        e.error
+                    })
             });
+        match result {
+            Ok(()) => Self::deposit_event(Event::<T>::CallDispatched {
+                proposal_hash: proposal,
+            }),
+            Err(error) => Self::deposit_event(Event::<T>::CallFailed {
+                proposal_hash: proposal,
+                error,
+            }),
         }
+        weight
+    }
+
+    /// Slashes a voter revealing after the end of the reveal phase and gives
+    /// back the voting tokens of the commit. The slash is kept unclaimed in
+    /// the pot.
+    pub fn reveal_late(
+        who: &T::AccountId,
+        proposal: T::Hash,
+        commit: &CommitOf<T>,
+    ) -> DispatchResult {
+        let pot_address = Self::account_id();
+        let slashed = Self::slash_voting_side(
+            vec![(who.clone(), commit.number.saturated_into())],
+            &pot_address,
+        )?;
+        Self::record_pot(proposal, slashed, BalanceOf::<T>::default(), false);
+        Self::refund_votes(who, commit.number);
+        <Reputations<T>>::mutate(who, |reputation| reputation.late_reveals.saturating_inc());
+        Self::report_reputation(who);
+        // probably need to refund, but let it be additional punishment
         Ok(())
     }
 
+    /// Removes up to `limit` commits of a closed proposal that were never
+    /// revealed. Each voter loses `NonRevealPenalty` of their reserved funds
+    /// to the pot, where it is kept unclaimed, and their tokens are handled
+    /// according to `UnrevealedTokens`. Returns the number of reaped commits.
+    pub fn reap_commits(proposal: T::Hash, limit: u32) -> Result<u32, DispatchError> {
+        let pot_address = Self::account_id();
+        let voters: Vec<T::AccountId> =
+            <Committers<T>>::iter_key_prefix(proposal).take(limit as usize).collect();
+        let mut total = BalanceOf::<T>::default();
+        for voter in voters.iter() {
+            <Committers<T>>::remove(proposal, voter);
+            let commit = <Commits<T>>::take(voter, proposal);
+            let slashed = Self::slash_reserve(voter, &pot_address, T::NonRevealPenalty::get())?;
+            total = total.saturating_add(slashed);
+            if let (Some(commit), TokenPolicy::Release) = (commit, T::UnrevealedTokens::get()) {
+                Self::refund_votes(voter, commit.number);
+            }
+            <Reputations<T>>::mutate(voter, |reputation| reputation.unrevealed.saturating_inc());
+            Self::report_reputation(voter);
+            Self::deposit_event(Event::<T>::Reaped {
+                account: voter.clone(),
+                proposal_hash: proposal,
+                slashed,
+            });
+        }
+        Self::record_pot(proposal, total, BalanceOf::<T>::default(), false);
+        Ok(voters.len() as u32)
+    }
+
+    /// Moves `fraction` of the funds reserved by a voter into the pot and
+    /// returns the amount that was actually moved
+    pub fn slash_reserve(
+        voter: &T::AccountId,
+        pot: &T::AccountId,
+        fraction: Perbill,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let slash = fraction.mul_floor(Self::stake(voter));
+        let lost = T::Currency::repatriate_reserved(
+            voter,
+            pot,
+            slash,
+            frame_support::traits::BalanceStatus::Reserved,
+        )?;
+        let slashed = slash.saturating_sub(lost);
+        Self::reduce_reserved_balance(voter, slashed);
+        <Reputations<T>>::mutate(voter, |reputation| {
+            reputation.slashed = reputation.slashed.saturating_add(slashed);
+        });
+        Ok(slashed)
+    }
+
+    /// Removes a closed proposal from the active set and keeps only its
+    /// outcome until the retention period ends
+    pub fn archive_proposal(
+        proposal: T::Hash,
+        proposal_data: &types::Proposal<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+        outcome: Outcome,
+        winners: types::Winners,
+    ) -> DispatchResult {
+        let current_block = frame_system::Pallet::<T>::block_number();
+        Self::schedule_deadline(
+            current_block + T::ResultRetention::get(),
+            proposal,
+            Phase::Archived,
+        )?;
+        <Proposals<T>>::mutate(|proposals| proposals.retain(|hash| hash != &proposal));
+        <ProposalData<T>>::remove(proposal);
+        <ProposalResults<T>>::insert(
+            proposal,
+            types::ProposalResult {
+                outcome,
+                winners,
+                tally: proposal_data.tally.clone(),
+                breakdown: types::breakdown(&proposal_data.tally),
+                payout: proposal_data.payout,
+                closed_at: current_block,
+            },
+        );
+        Ok(())
+    }
+
+    /// Removes an open proposal without deciding an outcome: the bond of its
+    /// proposer is given back and its deadline is unscheduled. Its commits
+    /// and votes are left to `refund_voters`.
+    pub fn remove_proposal(
+        proposal: T::Hash,
+        proposal_data: &types::Proposal<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+    ) {
+        if let Some(bond) = <ProposalBonds<T>>::take(proposal) {
+            T::Currency::unreserve(&proposal_data.proposer, bond);
+        }
+
+        let deadline = proposal_data.reveal_end.unwrap_or(proposal_data.poll_end);
+        Self::unschedule_deadline(deadline, proposal);
+        if let Some(target) = <NoteTargets<T>>::take(proposal) {
+            <TargetNotes<T>>::remove(Self::target_key(&target), proposal);
+        }
+        <ProposalCalls<T>>::remove(proposal);
+        <ProposalData<T>>::remove(proposal);
+        <Proposals<T>>::mutate(|proposals| proposals.retain(|hash| *hash != proposal));
+    }
+
+    /// Gives back the credits of up to `limit` commits and votes on a
+    /// proposal, commits first, and removes them. Returns how many were
+    /// refunded.
+    pub fn refund_voters(proposal: T::Hash, limit: u32) -> u32 {
+        let committers: Vec<T::AccountId> =
+            <Committers<T>>::iter_key_prefix(proposal).take(limit as usize).collect();
+        let mut refunded = committers.len() as u32;
+        for voter in committers.iter() {
+            <Committers<T>>::remove(proposal, voter);
+            if let Some(commit) = <Commits<T>>::take(voter, proposal) {
+                Self::return_votes(voter, commit.number);
+            }
+        }
+        let votes: Vec<(T::AccountId, VoteRecordOf<T>)> = <Votes<T>>::iter_prefix(proposal)
+            .take(limit.saturating_sub(refunded) as usize)
+            .collect();
+        for (voter, vote) in votes.iter() {
+            <Votes<T>>::remove(proposal, voter);
+            Self::return_votes(voter, vote.number);
+        }
+        refunded.saturating_accrue(votes.len() as u32);
+        refunded
+    }
+
+    /// Whether commits or votes on a proposal are still stored
+    pub fn has_voters(proposal: T::Hash) -> bool {
+        <Committers<T>>::iter_prefix(proposal).next().is_some()
+            || <Votes<T>>::iter_prefix(proposal).next().is_some()
+    }
+
+    /// Removes a member and its delegations and gives back its reserved
+    /// funds. Returns the funds given back and the delegations removed.
+    pub fn remove_member(who: &T::AccountId) -> (BalanceOf<T>, u32) {
+        // find the exact amount of reserved funds that need to be returned to the free
+        // balance
+        let reserved_balance = <Members<T>>::get(who).reserved_balance;
+        let missing = T::Currency::unreserve(who, reserved_balance);
+        let balance = reserved_balance.saturating_sub(missing);
+        // remove entries
+        <Members<T>>::remove(who);
+        <Suspended<T>>::remove(who);
+        <Viewpoints<T>>::remove(who);
+        <ViewpointChanges<T>>::remove(who);
+        <CreditEpochs<T>>::remove(who);
+        (balance, Self::clear_delegations(who))
+    }
+
+    /// The bonds reserved by `who` for the active proposals it made
+    pub fn bonds_of(who: &T::AccountId) -> BalanceOf<T> {
+        <Proposals<T>>::get()
+            .iter()
+            .filter(|proposal| {
+                <ProposalData<T>>::get(proposal).is_some_and(|data| &data.proposer == who)
+            })
+            .filter_map(<ProposalBonds<T>>::get)
+            .fold(Zero::zero(), |total: BalanceOf<T>, bond| {
+                total.saturating_add(bond)
+            })
+    }
+
+    /// Drops the pending commits of `who` and takes the votes it revealed
+    /// on active proposals out of their tally. The votes given on behalf of
+    /// its delegators are kept. Returns the number of commits and votes
+    /// dropped.
+    pub fn drop_votes(who: &T::AccountId) -> u32 {
+        let mut dropped = 0u32;
+        for (proposal, _) in <Commits<T>>::drain_prefix(who) {
+            <Committers<T>>::remove(proposal, who);
+            <ProposalData<T>>::mutate(proposal, |data| {
+                if let Some(data) = data {
+                    data.voters.saturating_dec();
+                }
+            });
+            dropped.saturating_inc();
+        }
+        for proposal in <Proposals<T>>::get() {
+            let Some(vote) = <Votes<T>>::take(proposal, who) else {
+                continue;
+            };
+            <ProposalData<T>>::mutate(proposal, |data| {
+                if let Some(data) = data {
+                    if let Some(votes) = data.tally.get_mut(vote.option as usize) {
+                        *votes = votes.saturating_sub(vote.number.saturated_into());
+                    }
+                    data.revealed.saturating_dec();
+                    data.voters.saturating_dec();
+                }
+            });
+            dropped.saturating_inc();
+        }
+        dropped
+    }
+
+    /// Removes a proposal from the phases to close at block `when`
+    pub fn unschedule_deadline(when: BlockNumberFor<T>, proposal: T::Hash) {
+        <Deadlines<T>>::mutate(when, |deadlines| {
+            deadlines.retain(|(hash, _)| *hash != proposal)
+        });
+    }
+
+    /// Removes the outcome of a proposal once its retention period is over
+    pub fn do_prune_result(proposal: T::Hash) -> DispatchResult {
+        let result = <ProposalResults<T>>::get(proposal);
+        ensure!(result.is_some(), Error::<T>::ResultMissing);
+        let result = result.unwrap();
+
+        let current_block = frame_system::Pallet::<T>::block_number();
+        ensure!(
+            result.closed_at + T::ResultRetention::get() <= current_block,
+            Error::<T>::RetentionNotExpired
+        );
+
+        <ProposalResults<T>>::remove(proposal);
+        <PotInflows<T>>::remove(proposal);
+        if let Some(target) = <NoteTargets<T>>::take(proposal) {
+            <TargetNotes<T>>::remove(Self::target_key(&target), proposal);
+        }
+        Self::deposit_event(Event::<T>::ResultPruned(proposal));
+
+        Ok(())
+    }
+
+    /// Queues the end of `phase` of a proposal to be processed in
+    /// `on_initialize` of block `when`. Fails if the queue of that block is
+    /// full, so that no phase is left without a deadline.
+    pub fn schedule_deadline(
+        when: BlockNumberFor<T>,
+        proposal: T::Hash,
+        phase: Phase,
+    ) -> DispatchResult {
+        <Deadlines<T>>::try_append(when, (proposal, phase))
+            .map_err(|_| Error::<T>::TooManyDeadlines.into())
+    }
+
+    /// Whether another phase can end at block `when`
+    pub fn can_schedule(when: BlockNumberFor<T>) -> bool {
+        <Deadlines<T>>::decode_len(when).unwrap_or_default() < MaxDeadlines::<T>::get() as usize
+    }
+
+    /// Closes the phases that ended by block `now`, at most
+    /// `MaxClosesPerBlock` of them. The rest stay queued and are closed first
+    /// in the next blocks, from `DeadlineCursor` on. Phases that have already
+    /// been closed manually are skipped.
+    pub fn process_deadlines(now: BlockNumberFor<T>) -> Weight {
+        let max = T::MaxClosesPerBlock::get();
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        let mut block = <DeadlineCursor<T>>::get().unwrap_or(now);
+        let mut closed = 0u32;
+        // a lagging cursor visits more blocks than it closes phases in, so
+        // that it catches up
+        let mut visited = 0u32;
+        while block <= now && closed < max && visited <= max {
+            visited.saturating_inc();
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            let mut left = Vec::new();
+            for (proposal, phase) in <Deadlines<T>>::take(block) {
+                if closed >= max {
+                    left.push((proposal, phase));
+                    continue;
+                }
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+                if Self::is_due(proposal, phase) {
+                    closed.saturating_inc();
+                    weight.saturating_accrue(Self::close_deadline(now, proposal, phase));
+                }
+            }
+            if !left.is_empty() {
+                <Deadlines<T>>::insert(block, BoundedVec::truncate_from(left));
+                break;
+            }
+            block.saturating_inc();
+        }
+        if block > now {
+            <DeadlineCursor<T>>::kill();
+        } else {
+            <DeadlineCursor<T>>::put(block);
+        }
+        weight
+    }
+
+    /// Whether a phase queued in `Deadlines` is still open
+    fn is_due(proposal: T::Hash, phase: Phase) -> bool {
+        match phase {
+            Phase::Commit => {
+                <ProposalData<T>>::get(proposal).is_some_and(|data| data.reveal_end.is_none())
+            }
+            Phase::Reveal => <ProposalData<T>>::get(proposal).is_some_and(|data| !data.closed),
+            Phase::Archived => <ProposalResults<T>>::contains_key(proposal),
+        }
+    }
+
+    /// Closes a phase in `on_initialize`. On failure the phase is queued
+    /// again `min_length` blocks later and `AutoCloseFailed` is emitted.
+    /// Returns the weight consumed.
+    fn close_deadline(now: BlockNumberFor<T>, proposal: T::Hash, phase: Phase) -> Weight {
+        let (result, weight) = match phase {
+            Phase::Commit => (
+                with_storage_layer(|| Self::do_close_vote(proposal)),
+                T::WeightInfo::close_vote(),
+            ),
+            Phase::Reveal => match with_storage_layer(|| Self::do_close_reveal(proposal)) {
+                Ok(weight) => (Ok(()), weight),
+                Err(error) => (
+                    Err(error),
+                    T::WeightInfo::close_reveal(T::MaxVotersPerProposal::get())
+                        .saturating_add(T::WeightInfo::reap_unrevealed(T::ReapLimit::get())),
+                ),
+            },
+            Phase::Archived => (
+                with_storage_layer(|| Self::do_prune_result(proposal)),
+                T::WeightInfo::prune_result(),
+            ),
+        };
+        let Err(error) = result else {
+            return weight;
+        };
+
+        let retry = now.saturating_add(Self::min_length());
+        let retry_at = Self::schedule_deadline(retry, proposal, phase).ok().map(|_| retry);
+        log::warn!(
+            target: LOG_TARGET,
+            "{:?} phase of {:?} not closed: {:?}, retrying at {:?}",
+            phase,
+            proposal,
+            error,
+            retry_at
+        );
+        Self::deposit_event(Event::<T>::AutoCloseFailed {
+            proposal_hash: proposal,
+            phase,
+            error,
+            retry_at,
+        });
+        weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    /// The deposit reserved on joining the committee
+    pub fn entry_fee() -> BalanceOf<T> {
+        <Parameters<T>>::get().entry_fee.unwrap_or_else(T::BasicDeposit::get)
+    }
+
+    /// The length of the reveal phase of the proposals closing their commit
+    /// phase now
+    pub fn reveal_length() -> BlockNumberFor<T> {
+        <Parameters<T>>::get().reveal_length.unwrap_or_else(T::RevealLength::get)
+    }
+
+    /// The minimum length of the commit phase of new proposals
+    pub fn min_length() -> BlockNumberFor<T> {
+        <Parameters<T>>::get().min_length.unwrap_or_else(T::MinLength::get)
+    }
+
+    /// The credits a member is given on joining, and the most they can hold
+    pub fn max_voting_tokens() -> T::VoteCredit {
+        <Parameters<T>>::get().max_voting_tokens.unwrap_or_else(T::MaxVotingTokens::get)
+    }
+
+    /// The number of proposals that can be active in parallel
+    pub fn max_proposals() -> ProposalIndex {
+        <Parameters<T>>::get().max_proposals.unwrap_or_else(T::MaxProposals::get)
+    }
+
     /// Intermediate
     pub fn account_id() -> T::AccountId { T::PalletId::get().into_account_truncating() }
+
+    /// Active proposals with the phase they are in
+    pub fn active_proposals() -> Vec<ProposalInfo<T::AccountId, BlockNumberFor<T>, T::Hash>> {
+        <Proposals<T>>::get().into_iter().filter_map(Self::proposal_info).collect()
+    }
+
+    /// An active proposal. The tally is only disclosed once the reveal phase
+    /// started.
+    pub fn proposal_info(
+        proposal: T::Hash,
+    ) -> Option<ProposalInfo<T::AccountId, BlockNumberFor<T>, T::Hash>> {
+        let proposal_data = <ProposalData<T>>::get(proposal)?;
+        let revealing = proposal_data.reveal_end.is_some();
+        Some(ProposalInfo {
+            proposal_hash: proposal,
+            title: proposal_data.title,
+            target: <NoteTargets<T>>::get(proposal),
+            proposer: proposal_data.proposer,
+            phase: if revealing {
+                Phase::Reveal
+            } else {
+                Phase::Commit
+            },
+            poll_end: proposal_data.poll_end,
+            reveal_end: proposal_data.reveal_end,
+            options: proposal_data.options,
+            tally: revealing.then_some(proposal_data.tally),
+            revealed: proposal_data.revealed,
+        })
+    }
+
+    /// Identifies the content a note annotates. Notes about the same content
+    /// share it whatever the timestamp they give.
+    pub fn target_key(target: &NoteTargetOf<T>) -> T::Hash {
+        T::Hashing::hash_of(&(&target.platform, &target.content))
+    }
+
+    /// Active and archived proposals annotating the content of `target`
+    pub fn notes_for_target(target: &NoteTargetOf<T>) -> Vec<T::Hash> {
+        <TargetNotes<T>>::iter_key_prefix(Self::target_key(target)).collect()
+    }
+
+    /// The member `who` delegates to on `topic`. For a platform, its
+    /// delegation for every note applies when it has none for the platform.
+    pub fn delegate_of(who: &T::AccountId, topic: &Topic) -> Option<T::AccountId> {
+        let delegates = <Delegations<T>>::get(who);
+        let find = |topic: &Topic| {
+            delegates.iter().find(|(t, _)| t == topic).map(|(_, delegate)| delegate.clone())
+        };
+        find(topic).or_else(|| topic.as_ref().and_then(|_| find(&None)))
+    }
+
+    /// Ensures that `delegator` delegating to `delegate` on `topic` does not
+    /// close a cycle, and that the chain it starts is at most
+    /// `MaxDelegationDepth` long.
+    pub fn check_delegation_chain(
+        delegator: &T::AccountId,
+        delegate: &T::AccountId,
+        topic: &Topic,
+    ) -> DispatchResult {
+        let mut current = delegate.clone();
+        for _ in 0..T::MaxDelegationDepth::get() {
+            ensure!(current != *delegator, Error::<T>::DelegationCycle);
+            match Self::delegate_of(&current, topic) {
+                Some(next) => current = next,
+                None => return Ok(()),
+            }
+        }
+        Err(Error::<T>::DelegationTooDeep.into())
+    }
+
+    /// Removes `delegator` from the delegators of `delegate` on `topic`
+    pub fn remove_delegator(delegate: &T::AccountId, delegator: &T::AccountId, topic: &Topic) {
+        <Delegators<T>>::mutate_exists(delegate, |delegators| {
+            if let Some(list) = delegators {
+                list.retain(|(d, t)| !(d == delegator && t == topic));
+                if list.is_empty() {
+                    *delegators = None;
+                }
+            }
+        });
+    }
+
+    /// Removes the delegations of `who` and the ones to `who`. Returns how
+    /// many were removed.
+    pub fn clear_delegations(who: &T::AccountId) -> u32 {
+        let delegates = <Delegations<T>>::take(who);
+        for (topic, delegate) in delegates.iter() {
+            Self::remove_delegator(delegate, who, topic);
+            Self::deposit_event(Event::<T>::Undelegated {
+                delegator: who.clone(),
+                topic: topic.clone(),
+            });
+        }
+        let delegators = <Delegators<T>>::take(who);
+        for (delegator, topic) in delegators.iter() {
+            <Delegations<T>>::mutate_exists(delegator, |delegates| {
+                if let Some(list) = delegates {
+                    list.retain(|(t, d)| !(t == topic && d == who));
+                    if list.is_empty() {
+                        *delegates = None;
+                    }
+                }
+            });
+            Self::deposit_event(Event::<T>::Undelegated {
+                delegator: delegator.clone(),
+                topic: topic.clone(),
+            });
+        }
+        (delegates.len() + delegators.len()) as u32
+    }
+
+    /// Gives the votes of the members delegating to `delegate` on a proposal,
+    /// once it revealed `number` votes for `option`, and so on down the
+    /// chains of delegations up to `MaxDelegationDepth`. Each delegator gives
+    /// as many votes as its delegate, or as many as its credits afford at
+    /// quadratic cost. Delegators that committed on the proposal themselves,
+    /// already voted or are suspended are skipped. At most `MaxDelegators`
+    /// votes are given, returns how many were.
+    pub fn apply_delegations(
+        proposal: T::Hash,
+        proposal_data: &mut types::Proposal<T::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+        delegate: &T::AccountId,
+        number: T::VoteCredit,
+        option: types::OptionIndex,
+    ) -> u32 {
+        let topic: Topic = <NoteTargets<T>>::get(proposal).map(|target| target.platform);
+        let mut given = 0u32;
+        let mut level = vec![(delegate.clone(), number)];
+        for _ in 0..T::MaxDelegationDepth::get() {
+            let mut next = Vec::new();
+            for (delegate, number) in level {
+                for (delegator, _) in <Delegators<T>>::get(&delegate) {
+                    if given >= T::MaxDelegators::get()
+                        || proposal_data.voters >= T::MaxVotersPerProposal::get()
+                    {
+                        return given;
+                    }
+                    if Self::delegate_of(&delegator, &topic).as_ref() != Some(&delegate)
+                        || <Commits<T>>::contains_key(&delegator, proposal)
+                        || <Votes<T>>::contains_key(proposal, &delegator)
+                        || <Suspended<T>>::contains_key(&delegator)
+                    {
+                        continue;
+                    }
+
+                    Self::replenish_member(&delegator);
+                    let credits = <Members<T>>::get(&delegator).voting_tokens;
+                    let number = number.min(credits.integer_sqrt());
+                    let cost = Self::quadratic_cost(number);
+                    if number.is_zero()
+                        || !cost.is_some_and(|cost| Self::decrease_votes(&delegator, cost))
+                    {
+                        continue;
+                    }
+                    if let Some(votes) = proposal_data.tally.get_mut(option as usize) {
+                        *votes = votes.saturating_add(number.saturated_into());
+                    }
+                    <Votes<T>>::insert(proposal, &delegator, types::VoteRecord { number, option });
+                    proposal_data.voters.saturating_inc();
+                    proposal_data.revealed.saturating_inc();
+                    given.saturating_inc();
+                    Self::deposit_event(Event::<T>::DelegatedVote {
+                        delegator: delegator.clone(),
+                        delegate: delegate.clone(),
+                        proposal_hash: proposal,
+                        number,
+                    });
+                    next.push((delegator, number));
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            level = next;
+        }
+        given
+    }
+
+    /// Whether `target` is neither annotated by a note nor already a candidate
+    pub fn is_new_candidate(target: &NoteTargetOf<T>) -> bool {
+        let key = Self::target_key(target);
+        !<Candidates<T>>::contains_key(key)
+            && <TargetNotes<T>>::iter_key_prefix(key).next().is_none()
+    }
+
+    /// Fetches the feed set in the offchain local storage and submits its new
+    /// posts as candidates, at most `MaxImportBatch` of them, signed with an
+    /// importer key of the keystore. Returns the number of posts submitted.
+    pub fn import_feed(now: BlockNumberFor<T>) -> Result<u32, ImportError> {
+        if now < <NextImportAt<T>>::get() {
+            return Ok(0);
+        }
+        let url = offchain::feed_url()?;
+        let body = offchain::fetch(&url)?;
+        let mut candidates: Vec<NoteTargetOf<T>> = Vec::new();
+        for target in offchain::parse_feed::<T::Hashing>(&body)? {
+            let key = Self::target_key(&target);
+            if Self::is_new_candidate(&target)
+                && !candidates.iter().any(|t| Self::target_key(t) == key)
+            {
+                candidates.push(target);
+            }
+        }
+        candidates.truncate(T::MaxImportBatch::get() as usize);
+        if candidates.is_empty() {
+            return Ok(0);
+        }
+
+        let count = candidates.len() as u32;
+        let candidates: ImportBatchOf<T> = BoundedVec::truncate_from(candidates);
+        let import_at = <NextImportAt<T>>::get();
+        let (_, result) = Signer::<T, T::AuthorityId>::any_account()
+            .send_unsigned_transaction(
+                |account| ImportPayload {
+                    candidates: candidates.clone(),
+                    import_at,
+                    public: account.public.clone(),
+                },
+                |payload, signature| Call::submit_candidates { payload, signature },
+            )
+            .ok_or(ImportError::NoKey)?;
+        result.map_err(|_| ImportError::Submission)?;
+        Ok(count)
+    }
+
+    /// Candidates waiting for review, keyed by [`Pallet::target_key`]
+    pub fn candidates() -> Vec<(T::Hash, CandidateOf<T>)> { <Candidates<T>>::iter().collect() }
+
+    /// Voting tokens and reserved funds of a member
+    pub fn member(who: &T::AccountId) -> Option<VoterBalanceOf<T>> {
+        Self::is_member(who).then(|| <Members<T>>::get(who))
+    }
+
+    /// Proposals an account committed on without revealing yet, with the
+    /// number of votes of each commit
+    pub fn pending_commits(who: &T::AccountId) -> Vec<(T::Hash, T::VoteCredit)> {
+        <Commits<T>>::iter_prefix(who)
+            .map(|(proposal, commit)| (proposal, commit.number))
+            .collect()
+    }
+
+    /// Estimates what an account would be rewarded and slashed if the
+    /// proposal was closed now. A pending commit counts as not revealed.
+    pub fn estimate_payout(
+        who: &T::AccountId,
+        proposal: T::Hash,
+    ) -> Option<PayoutEstimate<BalanceOf<T>>> {
+        let proposal_data = <ProposalData<T>>::get(proposal)?;
+        let mut estimate = PayoutEstimate::default();
+        if <Commits<T>>::contains_key(who, proposal) {
+            estimate.slash = T::NonRevealPenalty::get().mul_floor(Self::stake(who));
+            return Some(estimate);
+        }
+
+        let votes: Vec<(T::AccountId, VoteRecordOf<T>)> =
+            <Votes<T>>::iter_prefix(proposal).collect();
+        let winners = T::OutcomeRule::winners(&proposal_data.tally, &votes);
+        let (losers, rewarded) = Self::sides(&votes, &winners);
+        if let Some((_, number)) = losers.iter().find(|(account, _)| account == who) {
+            estimate.slash = Self::side_slash(who, *number);
+        }
+        if let Some((_, number)) = rewarded.iter().find(|(account, _)| account == who) {
+            let pot = losers.iter().fold(BalanceOf::<T>::default(), |pot, (loser, number)| {
+                pot.saturating_add(Self::side_slash(loser, *number))
+            });
+            let weight =
+                rewarded.iter().fold(0u32, |weight, (_, number)| weight.saturating_add(*number));
+            estimate.reward = Self::reward_share(pot, *number, weight);
+        }
+        Some(estimate)
+    }
+}
+
+/// Ensures the origin is an approved proposal and returns its hash.
+pub struct EnsureVotingApproved<Hash>(PhantomData<Hash>);
+
+impl<O, Hash> EnsureOrigin<O> for EnsureVotingApproved<Hash>
+where
+    O: OriginTrait + From<RawOrigin<Hash>>,
+    for<'a> &'a O::PalletsOrigin: TryInto<&'a RawOrigin<Hash>>,
+    Hash: Clone + Default,
+{
+    type Success = Hash;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        if let Ok(RawOrigin::Approved(proposal)) = o.caller().try_into() {
+            return Ok(proposal.clone());
+        }
+        Err(o)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(RawOrigin::Approved(Hash::default())))
+    }
 }
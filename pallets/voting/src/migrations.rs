@@ -0,0 +1,598 @@
+//! Storage migrations of the voting pallet.
+
+pub mod v1 {
+    use codec::Decode;
+    use codec::Encode;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+    use sp_runtime::SaturatedConversion;
+
+    use super::v6::OldVoterBalance;
+    use crate::BalanceOf;
+    use crate::Commits;
+    use crate::Config;
+    use crate::Pallet;
+
+    /// `Members` as it was stored before v6. Only existing entries are
+    /// changed, so the counter is left alone.
+    #[frame_support::storage_alias]
+    type Members<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        <T as frame_system::Config>::AccountId,
+        OldVoterBalance<BalanceOf<T>>,
+    >;
+
+    /// Commit layout before v1: a signature over `(vote, salt)` with the salt
+    /// stored in the clear.
+    #[derive(Encode, Decode)]
+    pub struct OldCommit<Signature> {
+        pub signature: Signature,
+        pub number: u8,
+        pub salt: u32,
+    }
+
+    /// Old commits cannot be converted into hash commitments, since the
+    /// pallet never learns the vote. Every pending commit is dropped and the
+    /// voting tokens spent on it are given back to the voter, who can commit
+    /// again with a proper commitment.
+    pub struct InnerMigrateToV1<T, Signature>(PhantomData<(T, Signature)>);
+
+    impl<T: Config, Signature: Decode> UncheckedOnRuntimeUpgrade for InnerMigrateToV1<T, Signature> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Commits::<T>::translate::<OldCommit<Signature>, _>(|who, _, old| {
+                translated += 1;
+                let max: u8 = T::MaxVotingTokens::get().saturated_into();
+                Members::<T>::mutate_exists(&who, |balance| {
+                    if let Some(balance) = balance {
+                        balance.voting_tokens = balance
+                            .voting_tokens
+                            .saturating_add(old.number.saturating_pow(2))
+                            .min(max);
+                    }
+                });
+                None
+            });
+            // one read and one write for the commit, same for the member balance
+            T::DbWeight::get().reads_writes(translated * 2, translated * 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            ensure!(
+                Commits::<T>::iter().next().is_none(),
+                "old commits must be cleared"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates `Commits` from signature-plus-salt to hash commitments.
+    pub type MigrateToV1<T, Signature> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateToV1<T, Signature>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+
+    use super::v4::OldProposal;
+    use super::v4::OldProposalResult;
+    use crate::types::winners;
+    use crate::types::Outcome;
+    use crate::types::Phase;
+    use crate::BalanceOf;
+    use crate::Config;
+    use crate::Pallet;
+    use crate::Proposals;
+
+    /// `ProposalData` as it was stored before v4.
+    #[frame_support::storage_alias]
+    type ProposalData<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        <T as frame_system::Config>::Hash,
+        OldProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+    >;
+
+    /// `ProposalResults` as it was stored before v4.
+    #[frame_support::storage_alias]
+    type ProposalResults<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        <T as frame_system::Config>::Hash,
+        OldProposalResult<BlockNumberFor<T>, BalanceOf<T>>,
+    >;
+
+    /// Before v2 closed proposals were never removed from `Proposals`, so a
+    /// chain could get stuck at `MaxProposals`. Every closed proposal is
+    /// archived, with the upgrade block as its closing block, and hashes
+    /// without data are dropped.
+    pub struct InnerMigrateToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut reads = 1u64;
+            let mut writes = 0u64;
+            for proposal in Proposals::<T>::get() {
+                reads += 1;
+                match ProposalData::<T>::get(proposal) {
+                    Some(data) if data.closed => {
+                        let outcome = Outcome::of(&winners(&[data.ayes, data.nays]));
+                        Proposals::<T>::mutate(|proposals| {
+                            proposals.retain(|hash| hash != &proposal)
+                        });
+                        ProposalData::<T>::remove(proposal);
+                        ProposalResults::<T>::insert(
+                            proposal,
+                            OldProposalResult {
+                                outcome,
+                                ayes: data.ayes,
+                                nays: data.nays,
+                                payout: data.payout,
+                                closed_at: now,
+                            },
+                        );
+                        // a result whose deadline does not fit is left to
+                        // `prune_result`
+                        let _ = Pallet::<T>::schedule_deadline(
+                            now + T::ResultRetention::get(),
+                            proposal,
+                            Phase::Archived,
+                        );
+                        // proposals, proposal data, results and deadlines
+                        writes += 4;
+                    }
+                    Some(_) => {}
+                    None => {
+                        Proposals::<T>::mutate(|proposals| {
+                            proposals.retain(|hash| hash != &proposal)
+                        });
+                        writes += 1;
+                    }
+                }
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for proposal in Proposals::<T>::get() {
+                let data =
+                    ProposalData::<T>::get(proposal).ok_or("active proposal without data")?;
+                ensure!(!data.closed, "closed proposal left in the active set");
+            }
+            Ok(())
+        }
+    }
+
+    /// Moves closed proposals out of the active set.
+    pub type MigrateToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+
+    use crate::Commits;
+    use crate::Committers;
+    use crate::Config;
+    use crate::Pallet;
+
+    /// Indexes the pending commits by proposal so unrevealed ones can be
+    /// reaped once the proposal is closed.
+    pub struct InnerMigrateToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut indexed = 0u64;
+            for (who, proposal) in Commits::<T>::iter_keys() {
+                Committers::<T>::insert(proposal, who, ());
+                indexed += 1;
+            }
+            T::DbWeight::get().reads_writes(indexed, indexed)
+        }
+    }
+
+    /// Builds the `Committers` index.
+    pub type MigrateToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v4 {
+    use codec::Decode;
+    use codec::Encode;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use scale_info::prelude::vec;
+    use scale_info::prelude::vec::Vec;
+
+    use super::v5;
+    use crate::types::breakdown;
+    use crate::types::winners;
+    use crate::types::Data;
+    use crate::types::OptionIndex;
+    use crate::types::Outcome;
+    use crate::types::ProposalResult;
+    use crate::types::RatingLabel;
+    use crate::types::RatingOptions;
+    use crate::types::Tally;
+    use crate::BalanceOf;
+    use crate::Config;
+    use crate::Pallet;
+    use crate::ProposalResults;
+
+    /// `ProposalData` as it was stored from v4 to v5.
+    #[frame_support::storage_alias]
+    type ProposalData<T: Config> = StorageMap<
+        Pallet<T>,
+        Identity,
+        <T as frame_system::Config>::Hash,
+        v5::OldProposal<<T as frame_system::Config>::AccountId, BlockNumberFor<T>, BalanceOf<T>>,
+    >;
+
+    /// Proposal layout before v4, with a yes/no tally. Votes were encoded as
+    /// `Yes = 0` and `No = 1`.
+    #[derive(Encode, Decode)]
+    pub struct OldProposal<AccountId, BlockNumber, Balance> {
+        pub title: Data,
+        pub proposer: AccountId,
+        pub ayes: u32,
+        pub nays: u32,
+        pub poll_end: BlockNumber,
+        pub reveal_end: Option<BlockNumber>,
+        pub votes: Vec<(AccountId, u8, OptionIndex)>,
+        pub revealed: Vec<AccountId>,
+        pub payout: Balance,
+        pub closed: bool,
+    }
+
+    /// Result layout before v4.
+    #[derive(Encode, Decode)]
+    pub struct OldProposalResult<BlockNumber, Balance> {
+        pub outcome: Outcome,
+        pub ayes: u32,
+        pub nays: u32,
+        pub payout: Balance,
+        pub closed_at: BlockNumber,
+    }
+
+    /// The options of proposals created before rating options existed. The
+    /// indexes match the encoding of the old votes, so pending commitments
+    /// stay valid.
+    pub fn yes_no_options() -> RatingOptions {
+        RatingOptions::truncate_from(vec![
+            RatingLabel::truncate_from(b"Yes".to_vec()),
+            RatingLabel::truncate_from(b"No".to_vec()),
+        ])
+    }
+
+    /// Turns the yes/no tallies of proposals and results into tallies of the
+    /// options `Yes` and `No`.
+    pub struct InnerMigrateToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            ProposalData::<T>::translate::<OldProposal<_, _, _>, _>(|_, old| {
+                translated += 1;
+                Some(v5::OldProposal {
+                    title: old.title,
+                    proposer: old.proposer,
+                    options: yes_no_options(),
+                    tally: Tally::truncate_from(vec![old.ayes, old.nays]),
+                    poll_end: old.poll_end,
+                    reveal_end: old.reveal_end,
+                    votes: old.votes,
+                    revealed: old.revealed,
+                    payout: old.payout,
+                    closed: old.closed,
+                })
+            });
+            ProposalResults::<T>::translate::<OldProposalResult<_, _>, _>(|_, old| {
+                translated += 1;
+                let tally = Tally::truncate_from(vec![old.ayes, old.nays]);
+                Some(ProposalResult {
+                    outcome: old.outcome,
+                    winners: winners(&tally),
+                    breakdown: breakdown(&tally),
+                    tally,
+                    payout: old.payout,
+                    closed_at: old.closed_at,
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Moves proposals and results to per option tallies.
+    pub type MigrateToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v5 {
+    use codec::Decode;
+    use codec::Encode;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    use scale_info::prelude::vec::Vec;
+
+    use super::v6::OldVoteRecord;
+    use crate::types::Data;
+    use crate::types::OptionIndex;
+    use crate::types::Proposal;
+    use crate::types::RatingOptions;
+    use crate::types::Tally;
+    use crate::Committers;
+    use crate::Config;
+    use crate::Pallet;
+    use crate::ProposalData;
+
+    /// `Votes` as it was stored before v6.
+    #[frame_support::storage_alias]
+    type Votes<T: Config> = StorageDoubleMap<
+        Pallet<T>,
+        Identity,
+        <T as frame_system::Config>::Hash,
+        Identity,
+        <T as frame_system::Config>::AccountId,
+        OldVoteRecord,
+    >;
+
+    /// Proposal layout before v5, with the votes stored inline.
+    #[derive(Encode, Decode)]
+    pub struct OldProposal<AccountId, BlockNumber, Balance> {
+        pub title: Data,
+        pub proposer: AccountId,
+        pub options: RatingOptions,
+        pub tally: Tally,
+        pub poll_end: BlockNumber,
+        pub reveal_end: Option<BlockNumber>,
+        pub votes: Vec<(AccountId, u8, OptionIndex)>,
+        pub revealed: Vec<AccountId>,
+        pub payout: Balance,
+        pub closed: bool,
+    }
+
+    /// Moves the revealed votes of active proposals into `Votes` and counts
+    /// their voters, so that proposals have a bounded encoding. Proposals that
+    /// already have more than `MaxVotersPerProposal` voters keep them, only
+    /// new commits are refused.
+    pub struct InnerMigrateToV5<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            ProposalData::<T>::translate::<OldProposal<_, _, _>, _>(|proposal, old| {
+                let pending = Committers::<T>::iter_key_prefix(proposal).count() as u32;
+                for (voter, number, option) in old.votes {
+                    Votes::<T>::insert(proposal, voter, OldVoteRecord { number, option });
+                    writes += 1;
+                }
+                let revealed = old.revealed.len() as u32;
+                reads += 1 + pending as u64;
+                writes += 1;
+                Some(Proposal {
+                    title: old.title,
+                    proposer: old.proposer,
+                    options: old.options,
+                    tally: old.tally,
+                    poll_end: old.poll_end,
+                    reveal_end: old.reveal_end,
+                    voters: revealed.saturating_add(pending),
+                    revealed,
+                    payout: old.payout,
+                    closed: old.closed,
+                })
+            });
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            for (proposal, data) in ProposalData::<T>::iter() {
+                let votes = Votes::<T>::iter_prefix(proposal).count() as u32;
+                ensure!(data.revealed == votes, "revealed votes were not moved");
+            }
+            Ok(())
+        }
+    }
+
+    /// Moves the votes of proposals into their own map.
+    pub type MigrateToV5<T> = VersionedMigration<
+        4,
+        5,
+        InnerMigrateToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v6 {
+    use codec::Decode;
+    use codec::Encode;
+    use codec::MaxEncodedLen;
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+
+    use crate::types::Commit;
+    use crate::types::OptionIndex;
+    use crate::types::VoteRecord;
+    use crate::types::VoterBalance;
+    use crate::Commits;
+    use crate::Config;
+    use crate::Members;
+    use crate::Pallet;
+    use crate::Votes;
+
+    /// Commit layout before v6, with `u8` votes.
+    #[derive(Encode, Decode)]
+    pub struct OldCommit<Hash> {
+        pub commitment: Hash,
+        pub number: u8,
+    }
+
+    /// Vote layout before v6, with `u8` votes.
+    #[derive(Encode, Decode, MaxEncodedLen, TypeInfo)]
+    pub struct OldVoteRecord {
+        pub number: u8,
+        pub option: OptionIndex,
+    }
+
+    /// Member layout before v6, with `u8` credits.
+    #[derive(Encode, Decode, MaxEncodedLen, TypeInfo)]
+    pub struct OldVoterBalance<Balance> {
+        pub voting_tokens: u8,
+        pub reserved_balance: Balance,
+    }
+
+    /// Widens the credits of members and the votes of commits and revealed
+    /// votes from `u8` to `VoteCredit`.
+    pub struct InnerMigrateToV6<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Members::<T>::translate::<OldVoterBalance<_>, _>(|_, old| {
+                translated += 1;
+                Some(VoterBalance {
+                    voting_tokens: (old.voting_tokens as u32).into(),
+                    reserved_balance: old.reserved_balance,
+                })
+            });
+            Commits::<T>::translate::<OldCommit<_>, _>(|_, _, old| {
+                translated += 1;
+                Some(Commit {
+                    commitment: old.commitment,
+                    number: (old.number as u32).into(),
+                })
+            });
+            Votes::<T>::translate::<OldVoteRecord, _>(|_, _, old| {
+                translated += 1;
+                Some(VoteRecord {
+                    number: (old.number as u32).into(),
+                    option: old.option,
+                })
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok(Members::<T>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let members = u32::decode(&mut &state[..]).map_err(|_| "invalid state")?;
+            ensure!(
+                Members::<T>::iter().count() as u32 == members,
+                "members were lost"
+            );
+            Ok(())
+        }
+    }
+
+    /// Widens voting credits to `VoteCredit`.
+    pub type MigrateToV6<T> = VersionedMigration<
+        5,
+        6,
+        InnerMigrateToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v7 {
+    use frame_support::migrations::VersionedMigration;
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::ReservableCurrency;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use frame_support::weights::Weight;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::vec::Vec;
+
+    use crate::types::PotLedger;
+    use crate::Config;
+    use crate::Pallet;
+    use crate::Pot;
+
+    /// Starts the pot bookkeeping. Funds slashed before v7 were never paid
+    /// out, they are recorded as unclaimed so that governance can spend them.
+    pub struct InnerMigrateToV7<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let held = T::Currency::reserved_balance(&Pallet::<T>::account_id());
+            Pot::<T>::put(PotLedger {
+                inflow: held,
+                unclaimed: held,
+                ..Default::default()
+            });
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let held = T::Currency::reserved_balance(&Pallet::<T>::account_id());
+            ensure!(
+                Pot::<T>::get().available() == held,
+                "the pot must account for its reserved funds"
+            );
+            Ok(())
+        }
+    }
+
+    /// Records the funds already held in the pot.
+    pub type MigrateToV7<T> = VersionedMigration<
+        6,
+        7,
+        InnerMigrateToV7<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,22 +1,42 @@
-use codec::Encode;
 use frame_support::derive_impl;
+use frame_support::dispatch::DispatchResult;
 use frame_support::parameter_types;
+use frame_support::traits::EitherOfDiverse;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
 use frame_support::PalletId;
 use sp_core::sr25519;
 use sp_core::ConstU128;
 use sp_core::Pair;
 use sp_core::Public;
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+use sp_runtime::traits::Hash;
 use sp_runtime::traits::IdentifyAccount;
 use sp_runtime::traits::IdentityLookup;
 use sp_runtime::traits::Verify;
 use sp_runtime::BuildStorage;
 use sp_runtime::MultiSignature;
+use sp_runtime::Perbill;
+use sp_runtime::Permill;
 
 use crate as pallet_voting;
-use crate::types::Vote;
+use crate::outcome::Bridging;
+use crate::outcome::Majority;
+use crate::outcome::OutcomeRule;
+use crate::types::CreditPolicy;
+use crate::types::IdentityJudgement;
+use crate::types::OptionIndex;
+use crate::types::Outcome;
+use crate::types::Salt;
+use crate::types::TokenPolicy;
+use crate::types::Winners;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
+/// Transactions submitted by the offchain worker.
+pub type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+
 pub const UNIT: u128 = 1000000000000;
 
 /// Alias to 512-bit hash when used in the context of a transaction signature on
@@ -33,15 +53,35 @@ pub type BlockNumber = u64;
 /// Balance of an account.
 pub type Balance = u128;
 
+/// Voting credits of a member.
+pub type VoteCredit = u32;
+
+/// Indexes of the default rating options
+pub const HELPFUL: OptionIndex = 0;
+pub const SOMEWHAT_HELPFUL: OptionIndex = 1;
+pub const NOT_HELPFUL: OptionIndex = 2;
+
 /// should be random, but we leave it const for simplicity
-const SALT: u32 = 10u32;
+pub const SALT: Salt = [7u8; 32];
 
 parameter_types! {
     pub const EntryFee: Balance = 30_000 * UNIT;
     pub const MaxProposals: u32 = 10u32;
+    pub const ProposalBond: Balance = 1_000 * UNIT;
+    pub const BondSlash: Perbill = Perbill::from_percent(50);
     pub const RevealLength: BlockNumber = 50u64;
     pub const MinLength: BlockNumber = 100u64;
-    pub const MaxTokens: u8 = 100u8;
+    pub const ResultRetention: BlockNumber = 100u64;
+    pub const NonRevealPenalty: Perbill = Perbill::from_percent(20);
+    pub const SideSlash: Perbill = Perbill::from_percent(10);
+    pub const UnrevealedTokens: TokenPolicy = TokenPolicy::Release;
+    pub const ReapLimit: u32 = 2;
+    pub const MaxClosesPerBlock: u32 = 3;
+    pub const MaxBeneficiaries: u32 = 2;
+    pub const MaxVotersPerProposal: u32 = 100;
+    pub const MaxTokens: u32 = 100;
+    pub const MaxCallLen: u32 = 1024;
+    pub MaxCallWeight: Weight = Weight::from_parts(500_000_000, 64 * 1024);
     pub const VotingPalletId: PalletId = PalletId(*b"p/v8t1ng");
     pub const BasicDeposit: Balance = 0;
     pub const FieldDeposit: Balance = 0;
@@ -49,6 +89,40 @@ parameter_types! {
     pub const MaxSubAccounts: u32 = 100;
     pub const MaxAdditionalFields: u32 = 1;
     pub const MaxRegistrars: u32 = 20;
+    pub static MinJudgement: IdentityJudgement = IdentityJudgement::Unknown;
+    pub static Credits: CreditPolicy<BlockNumber, VoteCredit> = CreditPolicy::RefundOnClose;
+    pub static Bridged: bool = false;
+    pub const BridgingSupport: Permill = Permill::from_percent(60);
+    pub const MinClusters: u32 = 2;
+    pub const ImportInterval: BlockNumber = 5;
+    pub const MaxImportBatch: u32 = 4;
+    pub const MaxCandidates: u32 = 6;
+    pub const UnsignedPriority: u64 = 1 << 20;
+    pub const MaxDelegators: u32 = 3;
+    pub const MaxDelegationDepth: u32 = 2;
+    pub const ViewpointCooldown: BlockNumber = 20;
+    pub const CandidateLifetime: BlockNumber = 40;
+}
+
+/// Decides outcomes by majority, or with [`Bridging`] once `Bridged` is set.
+pub struct TestOutcome;
+
+impl OutcomeRule<Test> for TestOutcome {
+    fn winners(tally: &[u32], votes: &[(AccountId, pallet_voting::VoteRecordOf<Test>)]) -> Winners {
+        if Bridged::get() {
+            <Bridging<BridgingSupport, MinClusters> as OutcomeRule<Test>>::winners(tally, votes)
+        } else {
+            <Majority as OutcomeRule<Test>>::winners(tally, votes)
+        }
+    }
+
+    fn outcome(winners: &[OptionIndex]) -> Outcome {
+        if Bridged::get() {
+            <Bridging<BridgingSupport, MinClusters> as OutcomeRule<Test>>::outcome(winners)
+        } else {
+            <Majority as OutcomeRule<Test>>::outcome(winners)
+        }
+    }
 }
 
 // Configure a mock runtime to test the pallet.
@@ -98,7 +172,10 @@ impl pallet_identity::Config for Test {
     type MaxSubAccounts = MaxSubAccounts;
     type IdentityInformation = pallet_identity::legacy::IdentityInfo<MaxAdditionalFields>;
     type MaxRegistrars = MaxRegistrars;
-    type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+    type RegistrarOrigin = EitherOfDiverse<
+        frame_system::EnsureRoot<AccountId>,
+        pallet_voting::EnsureVotingApproved<H256>,
+    >;
     type OffchainSignature = Signature;
     type SigningPublicKey = <Signature as Verify>::Signer;
     type UsernameAuthorityOrigin = frame_system::EnsureRoot<AccountId>;
@@ -108,52 +185,197 @@ impl pallet_identity::Config for Test {
 }
 
 pub struct VotingIdentityProvider;
+
+impl VotingIdentityProvider {
+    /// The best judgement of the identity set by `account` itself
+    fn own_judgement(account: &AccountId) -> Option<IdentityJudgement> {
+        let (registration, _) = Identity::identity(account.clone())?;
+        let judgement = registration
+            .judgements
+            .iter()
+            .map(|(_, judgement)| match judgement {
+                pallet_identity::Judgement::KnownGood => IdentityJudgement::KnownGood,
+                pallet_identity::Judgement::Reasonable => IdentityJudgement::Reasonable,
+                _ => IdentityJudgement::Unknown,
+            })
+            .max()
+            .unwrap_or(IdentityJudgement::Unknown);
+        Some(judgement)
+    }
+}
+
 impl pallet_voting::IdentityProvider<AccountId> for VotingIdentityProvider {
-    fn check_existence(account: &AccountId) -> bool {
-        Identity::identity(account.clone()).is_some()
+    fn check_existence(account: &AccountId) -> bool { Self::judgement(account).is_some() }
+
+    fn judgement(account: &AccountId) -> Option<IdentityJudgement> { Self::own_judgement(account) }
+
+    fn super_of(account: &AccountId) -> Option<AccountId> {
+        Identity::super_of(account.clone()).map(|(parent, _)| parent)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_identity(account: &AccountId, display: &[u8]) -> DispatchResult {
+        <Self as pallet_voting::IdentitySeeder<AccountId>>::seed_identity(account, display)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_judgement(registrar: &AccountId, account: &AccountId) -> DispatchResult {
+        <Self as pallet_voting::IdentitySeeder<AccountId>>::seed_judgement(registrar, account)
     }
 }
 
+impl pallet_voting::IdentitySeeder<AccountId> for VotingIdentityProvider {
+    fn seed_identity(account: &AccountId, display: &[u8]) -> DispatchResult {
+        Identity::set_identity(
+            RuntimeOrigin::signed(account.clone()),
+            Box::new(identity_info(display)),
+        )
+        .map(|_| ())
+        .map_err(|e| e.error)
+    }
+
+    fn seed_judgement(registrar: &AccountId, account: &AccountId) -> DispatchResult {
+        let (registration, _) = Identity::identity(account.clone())
+            .ok_or(pallet_identity::Error::<Test>::NoIdentity)?;
+        let index = match Identity::registrars()
+            .iter()
+            .position(|r| r.as_ref().is_some_and(|r| &r.account == registrar))
+        {
+            Some(index) => index,
+            None => {
+                Identity::add_registrar(RuntimeOrigin::root(), registrar.clone())
+                    .map_err(|e| e.error)?;
+                Identity::registrars().len() - 1
+            }
+        };
+        Identity::provide_judgement(
+            RuntimeOrigin::signed(registrar.clone()),
+            index as u32,
+            account.clone(),
+            pallet_identity::Judgement::Reasonable,
+            BlakeTwo256::hash_of(&registration.info),
+        )
+        .map(|_| ())
+        .map_err(|e| e.error)
+    }
+}
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = Extrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
 impl pallet_voting::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
     type IdentityProvider = VotingIdentityProvider;
+    type IdentitySeeder = VotingIdentityProvider;
     type Currency = Balances;
     type BasicDeposit = EntryFee;
     type MaxProposals = MaxProposals;
-    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
-    type Signature = MultiSignature;
+    type ProposalBond = ProposalBond;
+    type BondSlash = BondSlash;
+    type ResultRetention = ResultRetention;
+    type NonRevealPenalty = NonRevealPenalty;
+    type SlashPolicy = pallet_voting::slashing::Percentage<SideSlash>;
+    type OutcomeRule = TestOutcome;
+    type MinJudgement = MinJudgement;
+    type PotOrigin = frame_system::EnsureRoot<AccountId>;
+    type ViewpointOrigin = frame_system::EnsureRoot<AccountId>;
+    type ViewpointCooldown = ViewpointCooldown;
+    type CancelOrigin = frame_system::EnsureRoot<AccountId>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type ParameterOrigin = frame_system::EnsureRoot<AccountId>;
+    type UnrevealedTokens = UnrevealedTokens;
+    type CreditPolicy = Credits;
+    type ReapLimit = ReapLimit;
+    type MaxClosesPerBlock = MaxClosesPerBlock;
+    type MaxBeneficiaries = MaxBeneficiaries;
+    type MaxVotersPerProposal = MaxVotersPerProposal;
     type RevealLength = RevealLength;
     type MinLength = MinLength;
+    type VoteCredit = VoteCredit;
     type MaxVotingTokens = MaxTokens;
     type PalletId = VotingPalletId;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type MaxCallLen = MaxCallLen;
+    type MaxCallWeight = MaxCallWeight;
+    type ImportInterval = ImportInterval;
+    type MaxImportBatch = MaxImportBatch;
+    type MaxCandidates = MaxCandidates;
+    type UnsignedPriority = UnsignedPriority;
+    type AuthorityId = pallet_voting::offchain::crypto::ImporterId;
+    type CandidateLifetime = CandidateLifetime;
+    type MaxDelegators = MaxDelegators;
+    type MaxDelegationDepth = MaxDelegationDepth;
 }
 
 pub fn get_charlie() -> AccountId { get_account_id_from_seed::<sr25519::Public>("Charlie") }
 pub fn get_alice() -> AccountId { get_account_id_from_seed::<sr25519::Public>("Alice") }
 pub fn get_bob() -> AccountId { get_account_id_from_seed::<sr25519::Public>("Bob") }
+pub fn get_dave() -> AccountId { get_account_id_from_seed::<sr25519::Public>("Dave") }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+    new_test_ext_with(pallet_voting::GenesisConfig::<Test>::default())
+}
+
+/// Builds genesis storage with the given voting genesis.
+pub fn new_test_ext_with(voting: pallet_voting::GenesisConfig<Test>) -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
     pallet_balances::GenesisConfig::<Test> {
         balances: vec![
             (get_charlie(), 20_000 * UNIT),
             (get_alice(), 1_000_000 * UNIT),
             (get_bob(), 1_000_000 * UNIT),
+            (get_dave(), 1_000_000 * UNIT),
         ],
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    voting.assimilate_storage(&mut t).unwrap();
     t.into()
 }
 
-pub fn generate(account: &str, vote: Vote) -> (sp_core::sr25519::Signature, u32) {
-    let pair: sp_core::sr25519::Pair = Pair::from_string(account, None).unwrap();
-    let payload = (vote, SALT).encode();
-    let payload = payload.as_slice().to_owned();
-    let signed = pair.sign(&payload);
-    (signed, SALT)
+/// An identity with the given display name, truncated to 32 bytes.
+pub fn identity_info(display: &[u8]) -> pallet_identity::legacy::IdentityInfo<MaxAdditionalFields> {
+    let display = display[..display.len().min(32)].to_vec();
+    pallet_identity::legacy::IdentityInfo {
+        display: pallet_identity::Data::Raw(display.try_into().unwrap()),
+        additional: Default::default(),
+        legal: Default::default(),
+        web: Default::default(),
+        riot: Default::default(),
+        twitter: Default::default(),
+        email: Default::default(),
+        pgp_fingerprint: Default::default(),
+        image: Default::default(),
+    }
+}
+
+/// Advances the chain to block `n`, running the pallet hooks on the way.
+pub fn run_to_block(n: BlockNumber) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        VotingModule::on_initialize(next);
+    }
+}
+
+/// Builds the commitment `account` submits for `option` on `proposal_hash`
+/// together with the salt it has to reveal later.
+pub fn generate(account: &AccountId, proposal_hash: H256, option: OptionIndex) -> (H256, Salt) {
+    let commitment = VotingModule::commitment_of(&option, &SALT, account, &proposal_hash);
+    (commitment, SALT)
 }
 
 /// Generate a crypto pair from seed.
@@ -0,0 +1,144 @@
+//! Fetching of the feed of candidate posts the offchain worker imports.
+//!
+//! The feed is a JSON array of posts, each with the `platform` hosting it, its
+//! `id` or URL on that platform, and optionally the `timestamp` it was
+//! published at in seconds since the Unix epoch:
+//!
+//! ```json
+//! [{ "platform": "x.com", "id": "1790000000000000000", "timestamp": 1715000000 }]
+//! ```
+//!
+//! The worker signs its imports with a key of type [`KEY_TYPE`] from the
+//! keystore of the node. Only imports signed by an account of the
+//! `Importers` of the pallet are accepted.
+
+use codec::Decode;
+use codec::Encode;
+use frame_support::sp_runtime::RuntimeDebug;
+use frame_system::offchain::SignedPayload;
+use frame_system::offchain::SigningTypes;
+use scale_info::prelude::vec::Vec;
+use scale_info::TypeInfo;
+use serde_json::Value;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::offchain::http;
+use sp_runtime::offchain::Duration;
+use sp_runtime::offchain::StorageKind;
+use sp_runtime::traits::Hash;
+
+use crate::types::NoteTarget;
+use crate::types::Platform;
+
+/// Key of the offchain local storage holding the URL of the feed, as raw
+/// UTF-8. The worker imports nothing while it is not set.
+pub const FEED_URL_KEY: &[u8] = b"voting::feed_url";
+
+/// How long the worker waits for the feed, in milliseconds
+pub const FETCH_TIMEOUT: u64 = 3_000;
+
+/// Key type of the keys the worker signs its imports with
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"vote");
+
+/// The sr25519 keys importers sign with.
+pub mod crypto {
+    use sp_runtime::app_crypto::app_crypto;
+    use sp_runtime::app_crypto::sr25519;
+    use sp_runtime::MultiSignature;
+    use sp_runtime::MultiSigner;
+
+    use super::KEY_TYPE;
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Signs imports for runtimes whose accounts are `MultiSigner`s.
+    pub struct ImporterId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for ImporterId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
+/// Posts of the feed signed by an importer, for the import accepted from
+/// block `import_at` only, so that it cannot be replayed.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct ImportPayload<Public, BlockNumber, Batch> {
+    /// The posts to import
+    pub candidates: Batch,
+    /// The `NextImportAt` of the pallet the import was made for
+    pub import_at: BlockNumber,
+    /// The key of the importer
+    pub public: Public,
+}
+
+impl<T: SigningTypes, BlockNumber: Encode, Batch: Encode> SignedPayload<T>
+    for ImportPayload<T::Public, BlockNumber, Batch>
+{
+    fn public(&self) -> T::Public { self.public.clone() }
+}
+
+/// Why the feed could not be imported.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum ImportError {
+    /// No feed URL is set in the offchain local storage
+    NoFeed,
+    /// The feed could not be fetched
+    Http(http::Error),
+    /// The feed did not answer with `200 OK`
+    Status(u16),
+    /// The feed is not a JSON array
+    InvalidFeed,
+    /// The transaction could not be submitted to the pool
+    Submission,
+    /// No key of type [`KEY_TYPE`] is in the keystore to sign the import
+    NoKey,
+}
+
+/// The URL of the feed set in the offchain local storage
+pub fn feed_url() -> Result<Vec<u8>, ImportError> {
+    sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, FEED_URL_KEY)
+        .filter(|url| !url.is_empty())
+        .ok_or(ImportError::NoFeed)
+}
+
+/// Fetches the body of the feed at `url`
+pub fn fetch(url: &[u8]) -> Result<Vec<u8>, ImportError> {
+    let url = core::str::from_utf8(url).map_err(|_| ImportError::NoFeed)?;
+    let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT));
+    let pending = http::Request::get(url)
+        .deadline(deadline)
+        .send()
+        .map_err(|_| ImportError::Http(http::Error::IoError))?;
+    let response = pending
+        .try_wait(deadline)
+        .map_err(|_| ImportError::Http(http::Error::DeadlineReached))?
+        .map_err(ImportError::Http)?;
+    if response.code != 200 {
+        return Err(ImportError::Status(response.code));
+    }
+    Ok(response.body().collect())
+}
+
+/// Parses the posts of a feed into note targets, the content being the hash
+/// of the id of the post. Posts without a platform or an id, or with a
+/// platform longer than a [`Platform`], are skipped.
+pub fn parse_feed<H: Hash>(body: &[u8]) -> Result<Vec<NoteTarget<H::Output>>, ImportError> {
+    let feed: Value = serde_json::from_slice(body).map_err(|_| ImportError::InvalidFeed)?;
+    let posts = feed.as_array().ok_or(ImportError::InvalidFeed)?;
+    Ok(posts.iter().filter_map(parse_post::<H>).collect())
+}
+
+fn parse_post<H: Hash>(post: &Value) -> Option<NoteTarget<H::Output>> {
+    let platform = post.get("platform")?.as_str()?;
+    let id = post.get("id")?.as_str()?;
+    let platform = Platform::try_from(platform.as_bytes().to_vec()).ok()?;
+    if platform.is_empty() || id.is_empty() {
+        return None;
+    }
+    Some(NoteTarget {
+        platform,
+        content: <H as sp_runtime::traits::Hash>::hash(id.as_bytes()),
+        timestamp: post.get("timestamp").and_then(Value::as_u64),
+    })
+}
@@ -0,0 +1,93 @@
+//! Rules deciding which options of a proposal win once its reveal phase is
+//! closed.
+
+use core::marker::PhantomData;
+
+use scale_info::prelude::collections::BTreeMap;
+use scale_info::prelude::vec;
+use sp_runtime::traits::Get;
+use sp_runtime::Permill;
+use sp_runtime::SaturatedConversion;
+
+use crate::types;
+use crate::types::OptionIndex;
+use crate::types::Outcome;
+use crate::types::Viewpoint;
+use crate::types::Winners;
+use crate::Config;
+use crate::Viewpoints;
+use crate::VoteRecordOf;
+
+/// Computes the winning options of a proposal.
+pub trait OutcomeRule<T: Config> {
+    /// The winning options given the `tally` of a proposal and the votes
+    /// revealed on it. More than one winner means a tie, none means no option
+    /// won, e.g. because nobody voted, and nobody is slashed or rewarded.
+    fn winners(tally: &[u32], votes: &[(T::AccountId, VoteRecordOf<T>)]) -> Winners;
+
+    /// The outcome of a proposal won by `winners`
+    fn outcome(winners: &[OptionIndex]) -> Outcome { Outcome::of(winners) }
+}
+
+/// The options with the most votes win.
+pub struct Majority;
+
+impl<T: Config> OutcomeRule<T> for Majority {
+    fn winners(tally: &[u32], _votes: &[(T::AccountId, VoteRecordOf<T>)]) -> Winners {
+        types::winners(tally)
+    }
+}
+
+/// Approves a note only when raters who usually disagree agree on it: the
+/// approving option needs at least `Support` of the votes given in every
+/// viewpoint cluster, and at least `MinClusters` clusters must have voted.
+/// Voters without a viewpoint count in the tally but not in any cluster.
+///
+/// A note that is not bridged is not approved. It is disapproved without
+/// slashing anyone, unless another option wins the tally on its own.
+pub struct Bridging<Support, MinClusters>(PhantomData<(Support, MinClusters)>);
+
+impl<T, Support, MinClusters> OutcomeRule<T> for Bridging<Support, MinClusters>
+where
+    T: Config,
+    Support: Get<Permill>,
+    MinClusters: Get<u32>,
+{
+    fn winners(tally: &[u32], votes: &[(T::AccountId, VoteRecordOf<T>)]) -> Winners {
+        // approving and total votes of each cluster
+        let mut clusters: BTreeMap<Viewpoint, (u32, u32)> = BTreeMap::new();
+        for (account, vote) in votes.iter() {
+            let Some(viewpoint) = <Viewpoints<T>>::get(account) else {
+                continue;
+            };
+            let number: u32 = vote.number.saturated_into();
+            let (approving, total) = clusters.entry(viewpoint).or_default();
+            if vote.option == types::APPROVING_OPTION {
+                *approving = approving.saturating_add(number);
+            }
+            *total = total.saturating_add(number);
+        }
+
+        let bridged = !clusters.is_empty()
+            && clusters.len() as u32 >= MinClusters::get()
+            && clusters.values().all(|(approving, total)| {
+                Permill::from_rational(*approving, *total) >= Support::get()
+            });
+        if bridged {
+            return Winners::truncate_from(vec![types::APPROVING_OPTION]);
+        }
+
+        let winners = types::winners(tally);
+        match winners[..] {
+            [winner] if winner != types::APPROVING_OPTION => winners,
+            _ => Winners::default(),
+        }
+    }
+
+    fn outcome(winners: &[OptionIndex]) -> Outcome {
+        match winners {
+            [] => Outcome::Disapproved,
+            _ => Outcome::of(winners),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Policies deciding how much a voter on the losing side of a proposal is
+//! slashed.
+
+use core::marker::PhantomData;
+
+use sp_runtime::traits::AtLeast32BitUnsigned;
+use sp_runtime::traits::Get;
+use sp_runtime::Perbill;
+
+/// Computes the slash of a voter on the losing side of a proposal.
+pub trait SlashPolicy<Balance> {
+    /// The part of `reserved` taken from a voter who gave `number` votes.
+    fn slash(reserved: Balance, number: u32) -> Balance;
+}
+
+/// Takes the same fraction of the reserved funds of every voter.
+pub struct Percentage<Fraction>(PhantomData<Fraction>);
+
+impl<Balance, Fraction> SlashPolicy<Balance> for Percentage<Fraction>
+where
+    Balance: AtLeast32BitUnsigned,
+    Fraction: Get<Perbill>,
+{
+    fn slash(reserved: Balance, _number: u32) -> Balance { Fraction::get().mul_floor(reserved) }
+}
+
+/// Takes `PerVote` of the reserved funds for every vote given, so voters who
+/// staked more votes on the losing option lose more. The slash never exceeds
+/// the reserved funds.
+pub struct StakeWeighted<PerVote>(PhantomData<PerVote>);
+
+impl<Balance, PerVote> SlashPolicy<Balance> for StakeWeighted<PerVote>
+where
+    Balance: AtLeast32BitUnsigned,
+    PerVote: Get<Perbill>,
+{
+    fn slash(reserved: Balance, number: u32) -> Balance {
+        let parts = PerVote::get().deconstruct().saturating_mul(number);
+        Perbill::from_parts(parts).mul_floor(reserved)
+    }
+}
+
+/// Applies the `Inner` policy but never takes more than `Cap`.
+pub struct Capped<Inner, Cap>(PhantomData<(Inner, Cap)>);
+
+impl<Balance, Inner, Cap> SlashPolicy<Balance> for Capped<Inner, Cap>
+where
+    Balance: AtLeast32BitUnsigned,
+    Inner: SlashPolicy<Balance>,
+    Cap: Get<Balance>,
+{
+    fn slash(reserved: Balance, number: u32) -> Balance {
+        Inner::slash(reserved, number).min(Cap::get())
+    }
+}
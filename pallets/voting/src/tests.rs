@@ -1,25 +1,167 @@
+use codec::Decode;
+use codec::Encode;
 use frame_support::assert_noop;
 use frame_support::assert_ok;
+use frame_support::dispatch::DispatchResult;
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::parameter_types;
+use frame_support::traits::Currency;
+use frame_support::traits::EnsureOrigin;
+use frame_support::traits::Get;
+use frame_support::traits::Hooks;
+use frame_support::traits::OnRuntimeUpgrade;
+use frame_support::traits::ReservableCurrency;
+use frame_support::traits::StorageVersion;
+use frame_support::weights::RuntimeDbWeight;
+use frame_support::weights::Weight;
+use frame_system::offchain::SignedPayload;
 use pallet_identity::legacy::IdentityInfo;
+use sp_core::offchain::testing;
+use sp_core::offchain::OffchainDbExt;
+use sp_core::offchain::OffchainWorkerExt;
+use sp_core::offchain::StorageKind;
+use sp_core::offchain::TransactionPoolExt;
+use sp_core::sr25519;
+use sp_core::H256;
+use sp_keystore::testing::MemoryKeystore;
+use sp_keystore::Keystore;
+use sp_keystore::KeystoreExt;
+use sp_runtime::traits::BlakeTwo256;
+use sp_runtime::traits::Hash;
+use sp_runtime::traits::IdentifyAccount;
+use sp_runtime::traits::ValidateUnsigned;
+use sp_runtime::transaction_validity::InvalidTransaction;
+use sp_runtime::transaction_validity::TransactionSource;
 use sp_runtime::BoundedVec;
+use sp_runtime::DispatchError;
+use sp_runtime::MultiSignature;
+use sp_runtime::MultiSigner;
+use sp_runtime::Perbill;
+use sp_runtime::Permill;
 
+use crate::migrations::v1;
+use crate::migrations::v2;
+use crate::migrations::v3;
+use crate::migrations::v4;
+use crate::migrations::v5;
+use crate::migrations::v6;
+use crate::migrations::v7;
 use crate::mock::generate;
 use crate::mock::get_alice;
 use crate::mock::get_bob;
+use crate::mock::get_charlie;
+use crate::mock::get_dave;
+use crate::mock::identity_info;
 use crate::mock::new_test_ext;
+use crate::mock::new_test_ext_with;
+use crate::mock::run_to_block;
+use crate::mock::AccountId;
+use crate::mock::Balance;
 use crate::mock::Balances;
+use crate::mock::BondSlash;
+use crate::mock::Bridged;
+use crate::mock::BridgingSupport;
+use crate::mock::CandidateLifetime;
+use crate::mock::Credits;
+use crate::mock::EntryFee;
+use crate::mock::Extrinsic;
 use crate::mock::Identity;
+use crate::mock::ImportInterval;
 use crate::mock::MaxAdditionalFields;
+use crate::mock::MaxBeneficiaries;
+use crate::mock::MaxCallLen;
+use crate::mock::MaxCallWeight;
+use crate::mock::MaxCandidates;
+use crate::mock::MaxClosesPerBlock;
+use crate::mock::MaxProposals;
 use crate::mock::MaxTokens;
+use crate::mock::MaxVotersPerProposal;
+use crate::mock::MinClusters;
+use crate::mock::MinJudgement;
+use crate::mock::MinLength;
+use crate::mock::NonRevealPenalty;
+use crate::mock::ProposalBond;
+use crate::mock::ReapLimit;
+use crate::mock::ResultRetention;
+use crate::mock::RevealLength;
+use crate::mock::RuntimeCall;
+use crate::mock::RuntimeEvent;
 use crate::mock::RuntimeOrigin;
+use crate::mock::SideSlash;
 use crate::mock::System;
 use crate::mock::Test;
+use crate::mock::ViewpointCooldown;
+use crate::mock::VoteCredit;
+use crate::mock::VotingIdentityProvider;
 use crate::mock::VotingModule;
+use crate::mock::HELPFUL;
+use crate::mock::NOT_HELPFUL;
+use crate::mock::SALT;
+use crate::mock::SOMEWHAT_HELPFUL;
+use crate::offchain;
+use crate::offchain::crypto::ImporterId;
+use crate::offchain::ImportError;
+use crate::offchain::ImportPayload;
+use crate::outcome::Bridging;
+use crate::outcome::Majority;
+use crate::outcome::OutcomeRule;
+use crate::slashing::Capped;
+use crate::slashing::Percentage;
+use crate::slashing::SlashPolicy;
+use crate::slashing::StakeWeighted;
+use crate::types;
+use crate::types::CreditPolicy;
 use crate::types::Data;
-use crate::types::Vote;
+use crate::types::IdentityJudgement;
+use crate::types::NoteTarget;
+use crate::types::Outcome;
+use crate::types::PayoutEstimate;
+use crate::types::Phase;
+use crate::types::PotLedger;
+use crate::types::PotSpend;
+use crate::types::ProposalResult;
+use crate::types::RatingLabel;
+use crate::types::RatingOptions;
+use crate::types::VoteRecord;
+use crate::types::VoterBalance;
+use crate::types::VotingParameter;
+use crate::types::MAX_VIEWPOINTS;
+use crate::CancelledProposals;
+use crate::Candidates;
+use crate::Commits;
+use crate::Committers;
+use crate::CreditEpochs;
+use crate::DeadlineCursor;
+use crate::Deadlines;
+use crate::Delegations;
+use crate::Delegators;
+use crate::EnsureVotingApproved;
 use crate::Error;
+use crate::Event;
+use crate::IdentitySeeder;
+use crate::ImportBatchOf;
+use crate::ImportPayloadOf;
+use crate::Importers;
+use crate::JudgementCursor;
+use crate::MaxDeadlines;
 use crate::Members;
+use crate::NextImportAt;
+use crate::NoteTargetOf;
+use crate::NoteTargets;
+use crate::Pot;
+use crate::PotInflows;
+use crate::ProposalBonds;
+use crate::ProposalCalls;
+use crate::ProposalData;
+use crate::ProposalResults;
 use crate::Proposals;
+use crate::ReplenishCursor;
+use crate::ReplenishedEpoch;
+use crate::Suspended;
+use crate::TargetNotes;
+use crate::ViewpointChanges;
+use crate::Viewpoints;
+use crate::Votes;
 
 #[test]
 fn not_join_without_identity() {
@@ -44,6 +186,203 @@ fn join_with_identity() {
     });
 }
 
+/// Has dave, a registrar, give `judgement` to the identity of `who`.
+fn judge(who: &AccountId, judgement: pallet_identity::Judgement<Balance>) {
+    if Identity::registrars().is_empty() {
+        assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), get_dave()));
+    }
+    let (registration, _) = Identity::identity(who.clone()).unwrap();
+    assert_ok!(Identity::provide_judgement(
+        RuntimeOrigin::signed(get_dave()),
+        0,
+        who.clone(),
+        judgement,
+        BlakeTwo256::hash_of(&registration.info),
+    ));
+}
+
+#[test]
+fn join_needs_a_judged_identity() {
+    new_test_ext().execute_with(|| {
+        MinJudgement::set(IdentityJudgement::Reasonable);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        assert_noop!(
+            VotingModule::join_committee(origin.clone()),
+            Error::<Test>::InsufficientJudgement
+        );
+
+        judge(&alice, pallet_identity::Judgement::LowQuality);
+        assert_noop!(
+            VotingModule::join_committee(origin.clone()),
+            Error::<Test>::InsufficientJudgement
+        );
+
+        judge(&alice, pallet_identity::Judgement::Reasonable);
+        assert_ok!(VotingModule::join_committee(origin));
+    });
+}
+
+#[test]
+fn subs_of_a_judged_identity_cannot_join() {
+    new_test_ext().execute_with(|| {
+        MinJudgement::set(IdentityJudgement::Reasonable);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        judge(&alice, pallet_identity::Judgement::Reasonable);
+        for sub in [get_bob(), get_charlie()] {
+            assert_ok!(Identity::add_sub(
+                origin.clone(),
+                sub,
+                pallet_identity::Data::None
+            ));
+        }
+
+        // the subs do not share the judgement of their parent, whether or
+        // not the parent is a member
+        for sub in [get_bob(), get_charlie()] {
+            assert_noop!(
+                VotingModule::join_committee(RuntimeOrigin::signed(sub)),
+                Error::<Test>::NoIdentity
+            );
+        }
+        assert_ok!(VotingModule::join_committee(origin));
+        for sub in [get_bob(), get_charlie()] {
+            assert_noop!(
+                VotingModule::join_committee(RuntimeOrigin::signed(sub)),
+                Error::<Test>::NoIdentity
+            );
+        }
+        assert!(!VotingModule::is_member(&get_bob()));
+        assert!(!VotingModule::is_member(&get_charlie()));
+    });
+}
+
+#[test]
+fn sub_identity_of_a_member_cannot_join() {
+    new_test_ext().execute_with(|| {
+        MinJudgement::set(IdentityJudgement::Reasonable);
+        let alice = get_alice();
+        let bob = get_bob();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        judge(&alice, pallet_identity::Judgement::Reasonable);
+        assert_ok!(Identity::add_sub(
+            origin.clone(),
+            bob.clone(),
+            pallet_identity::Data::None
+        ));
+        // a sub judged on its own identity
+        assert_ok!(Identity::set_identity(
+            RuntimeOrigin::signed(bob.clone()),
+            Box::new(data())
+        ));
+        judge(&bob, pallet_identity::Judgement::Reasonable);
+
+        assert_ok!(VotingModule::join_committee(origin.clone()));
+        assert_noop!(
+            VotingModule::join_committee(RuntimeOrigin::signed(bob.clone())),
+            Error::<Test>::ParentIsMember
+        );
+
+        assert_ok!(VotingModule::leave_committee(origin));
+        assert_ok!(VotingModule::join_committee(RuntimeOrigin::signed(bob)));
+    });
+}
+
+#[test]
+fn revoked_judgement_suspends_member() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MinJudgement::set(IdentityJudgement::Reasonable);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        judge(&alice, pallet_identity::Judgement::KnownGood);
+        assert_ok!(VotingModule::join_committee(origin.clone()));
+        assert_noop!(
+            VotingModule::refresh_judgement(origin.clone(), alice.clone()),
+            Error::<Test>::JudgementUnchanged
+        );
+
+        judge(&alice, pallet_identity::Judgement::Erroneous);
+        assert_ok!(VotingModule::refresh_judgement(
+            RuntimeOrigin::signed(get_bob()),
+            alice.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::MemberSuspended {
+                account: alice.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(<Suspended<Test>>::get(&alice), Some(1));
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(1)),
+                target(),
+                100,
+                None,
+                None
+            ),
+            Error::<Test>::MemberSuspended
+        );
+
+        judge(&alice, pallet_identity::Judgement::Reasonable);
+        assert_ok!(VotingModule::refresh_judgement(
+            origin.clone(),
+            alice.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::MemberReinstated {
+                account: alice.clone(),
+            }
+            .into(),
+        );
+        assert!(!<Suspended<Test>>::contains_key(&alice));
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None
+        ));
+    });
+}
+
+#[test]
+fn idle_blocks_check_judgements() {
+    new_test_ext().execute_with(|| {
+        let members = [get_alice(), get_bob()];
+        for member in members.iter() {
+            let origin = RuntimeOrigin::signed(member.clone());
+            assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+            assert_ok!(VotingModule::join_committee(origin));
+        }
+        MinJudgement::set(IdentityJudgement::Reasonable);
+
+        // room for a single member
+        let db: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        let one =
+            <() as crate::WeightInfo>::refresh_judgement().saturating_add(db.reads_writes(1, 1));
+        assert_eq!(
+            VotingModule::on_idle(1, one.saturating_sub(Weight::from_parts(1, 0))),
+            Weight::zero()
+        );
+        assert_eq!(VotingModule::on_idle(1, one), one);
+        assert_eq!(<Suspended<Test>>::iter().count(), 1);
+        assert!(<JudgementCursor<Test>>::get().is_some());
+
+        VotingModule::on_idle(1, Weight::MAX);
+        assert!(members.iter().all(<Suspended<Test>>::contains_key));
+        assert_eq!(<JudgementCursor<Test>>::get(), None);
+    });
+}
+
 #[test]
 fn disallow_action_for_non_members() {
     new_test_ext().execute_with(|| {
@@ -53,7 +392,10 @@ fn disallow_action_for_non_members() {
         let result = VotingModule::create_proposal(
             bob_origin,
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
         assert_noop!(result, Error::<Test>::NotMember);
     });
@@ -68,8 +410,14 @@ fn create_proposal_success() {
 
         let _ = VotingModule::join_committee(origin.clone());
 
-        let result =
-            VotingModule::create_proposal(origin, Box::new(Data::Raw(BoundedVec::default())), 100);
+        let result = VotingModule::create_proposal(
+            origin,
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
         assert_ok!(result);
 
         let results = <Proposals<Test>>::get();
@@ -89,10 +437,19 @@ fn no_proposal_duplicates() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
+        );
+        let result = VotingModule::create_proposal(
+            origin,
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
         );
-        let result =
-            VotingModule::create_proposal(origin, Box::new(Data::Raw(BoundedVec::default())), 100);
 
         assert_noop!(result, Error::<Test>::DuplicateProposal);
     });
@@ -110,15 +467,17 @@ fn submit_commits() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, _) = generate(&get_alice(), proposal_hash, HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let result = VotingModule::commit_vote(origin, proposal_hash, sig, 8, salt);
+        let result = VotingModule::commit_vote(origin, proposal_hash, commitment, 8);
         assert_ok!(result);
     });
 }
@@ -135,15 +494,17 @@ fn cannot_submit_votes_more_than_have() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, _) = generate(&get_alice(), proposal_hash, HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let result = VotingModule::commit_vote(origin, proposal_hash, sig, 11, salt);
+        let result = VotingModule::commit_vote(origin, proposal_hash, commitment, 11);
         assert_noop!(result, Error::<Test>::NotEnoughVotingTokens);
     });
 }
@@ -160,17 +521,19 @@ fn cannot_commit_after_deadline() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         System::set_block_number(System::block_number().saturating_add(105));
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, _) = generate(&get_alice(), proposal_hash, HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let result = VotingModule::commit_vote(origin, proposal_hash, sig, 5, salt);
+        let result = VotingModule::commit_vote(origin, proposal_hash, commitment, 5);
         assert_noop!(result, Error::<Test>::VoteEnded);
     });
 }
@@ -187,19 +550,29 @@ fn reveal_vote_success() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         System::set_block_number(System::block_number().saturating_add(20));
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, salt) = generate(&get_alice(), proposal_hash, HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, sig, 8, salt);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 8);
 
-        let result = VotingModule::reveal_vote(origin, proposal_hash, Vote::Yes);
+        // votes cannot be revealed while others are still committing
+        assert_noop!(
+            VotingModule::reveal_vote(origin.clone(), proposal_hash, HELPFUL, salt),
+            Error::<Test>::RevealNotStarted
+        );
+
+        System::set_block_number(<ProposalData<Test>>::get(proposal_hash).unwrap().poll_end);
+        assert_ok!(VotingModule::close_vote(origin.clone(), proposal_hash));
+        let result = VotingModule::reveal_vote(origin, proposal_hash, HELPFUL, salt);
         assert_ok!(result);
     });
 }
@@ -216,20 +589,24 @@ fn cannot_reveal_incorrect_vote() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         System::set_block_number(System::block_number().saturating_add(20));
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, salt) = generate(&get_alice(), proposal_hash, HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, sig, 8, salt);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 8);
+        System::set_block_number(<ProposalData<Test>>::get(proposal_hash).unwrap().poll_end);
+        assert_ok!(VotingModule::close_vote(origin.clone(), proposal_hash));
 
-        let result = VotingModule::reveal_vote(origin, proposal_hash, Vote::No);
-        assert_noop!(result, Error::<Test>::SignatureInvalid);
+        let result = VotingModule::reveal_vote(origin, proposal_hash, NOT_HELPFUL, salt);
+        assert_noop!(result, Error::<Test>::CommitmentMismatch);
     });
 }
 
@@ -245,7 +622,10 @@ fn close_vote_success() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         System::set_block_number(System::block_number().saturating_add(120));
@@ -267,7 +647,10 @@ fn cannot_close_vote_before_deadline() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         let proposal_hash = <Proposals<Test>>::get()[0];
@@ -288,7 +671,10 @@ fn close_reveal_success() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         System::set_block_number(110);
@@ -315,7 +701,10 @@ fn cannot_close_reveal_early() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         System::set_block_number(110);
@@ -342,7 +731,10 @@ fn cannot_close_reveal_before_vote_end() {
         let _ = VotingModule::create_proposal(
             origin.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         let proposal_hash = <Proposals<Test>>::get()[0];
@@ -370,28 +762,32 @@ fn slashed_correctly() {
         let _ = VotingModule::create_proposal(
             origin_alice.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, alice_salt) = generate(&get_alice(), proposal_hash, HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_alice, proposal_hash, sig, 8, salt);
+        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, commitment, 8);
 
-        let (sig, salt) = generate("//Bob", Vote::No);
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
+        let (commitment, bob_salt) = generate(&get_bob(), proposal_hash, NOT_HELPFUL);
 
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, sig, 2, salt);
+        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, commitment, 2);
 
         System::set_block_number(101);
 
         let proposal_hash = <Proposals<Test>>::get()[0];
         let _ = VotingModule::close_vote(origin_bob.clone(), proposal_hash);
 
+        let _ = VotingModule::reveal_vote(origin_alice, proposal_hash, HELPFUL, alice_salt);
+        let _ = VotingModule::reveal_vote(origin_bob.clone(), proposal_hash, NOT_HELPFUL, bob_salt);
+
         System::set_block_number(160);
 
         let alice_original_balance = <Members<Test>>::get(alice.clone()).reserved_balance;
@@ -424,33 +820,34 @@ fn votes_deducted_and_refunded() {
         let _ = VotingModule::create_proposal(
             origin_alice.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, sig, 8, salt);
+        let (commitment, alice_salt) = generate(&get_alice(), proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, commitment, 8);
 
         let alice_original_votes = <Members<Test>>::get(alice.clone()).voting_tokens;
-        assert!(alice_original_votes == MaxTokens::get() - 8_u8.pow(2));
+        assert!(alice_original_votes == MaxTokens::get() - 8_u32.pow(2));
 
-        let (sig, salt) = generate("//Bob", Vote::No);
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, sig, 2, salt);
+        let (commitment, bob_salt) = generate(&get_bob(), proposal_hash, NOT_HELPFUL);
+        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, commitment, 2);
 
         let bob_original_votes = <Members<Test>>::get(bob.clone()).voting_tokens;
-        assert!(bob_original_votes == MaxTokens::get() - 2_u8.pow(2));
+        assert!(bob_original_votes == MaxTokens::get() - 2_u32.pow(2));
 
         System::set_block_number(101);
 
         let proposal_hash = <Proposals<Test>>::get()[0];
         let _ = VotingModule::close_vote(origin_bob.clone(), proposal_hash);
 
-        let _ = VotingModule::reveal_vote(origin_alice, proposal_hash, Vote::Yes);
-        let _ = VotingModule::reveal_vote(origin_bob.clone(), proposal_hash, Vote::No);
+        let _ = VotingModule::reveal_vote(origin_alice, proposal_hash, HELPFUL, alice_salt);
+        let _ = VotingModule::reveal_vote(origin_bob.clone(), proposal_hash, NOT_HELPFUL, bob_salt);
 
         System::set_block_number(160);
 
@@ -475,15 +872,17 @@ fn cannot_leave_while_in_vote() {
         let _ = VotingModule::create_proposal(
             origin_alice.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, sig, 8, salt);
+        let (commitment, _) = generate(&get_alice(), proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, commitment, 8);
 
         let result = VotingModule::leave_committee(origin_alice.clone());
         assert_noop!(result, Error::<Test>::InMotion);
@@ -513,27 +912,28 @@ fn cashout() {
         let _ = VotingModule::create_proposal(
             origin_alice.clone(),
             Box::new(Data::Raw(BoundedVec::default())),
+            target(),
             100,
+            None,
+            None,
         );
 
         let results = <Proposals<Test>>::get();
         let proposal_hash = results[0];
 
-        let (sig, salt) = generate("//Alice", Vote::Yes);
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, sig, 8, salt);
+        let (commitment, alice_salt) = generate(&get_alice(), proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, commitment, 8);
 
-        let (sig, salt) = generate("//Bob", Vote::No);
-        let sig = sp_runtime::MultiSignature::Sr25519(sig);
-        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, sig, 2, salt);
+        let (commitment, bob_salt) = generate(&get_bob(), proposal_hash, NOT_HELPFUL);
+        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, commitment, 2);
 
         System::set_block_number(101);
 
         let proposal_hash = <Proposals<Test>>::get()[0];
         let _ = VotingModule::close_vote(origin_bob.clone(), proposal_hash);
 
-        let _ = VotingModule::reveal_vote(origin_alice.clone(), proposal_hash, Vote::Yes);
-        let _ = VotingModule::reveal_vote(origin_bob.clone(), proposal_hash, Vote::No);
+        let _ = VotingModule::reveal_vote(origin_alice.clone(), proposal_hash, HELPFUL, alice_salt);
+        let _ = VotingModule::reveal_vote(origin_bob.clone(), proposal_hash, NOT_HELPFUL, bob_salt);
 
         System::set_block_number(160);
 
@@ -546,16 +946,4177 @@ fn cashout() {
     });
 }
 
-fn data() -> IdentityInfo<MaxAdditionalFields> {
-    IdentityInfo {
-        display: pallet_identity::Data::Raw(b"ten".to_vec().try_into().unwrap()),
-        additional: BoundedVec::default(),
-        legal: Default::default(),
-        web: Default::default(),
-        riot: Default::default(),
-        twitter: Default::default(),
-        email: Default::default(),
-        pgp_fingerprint: Default::default(),
-        image: Default::default(),
-    }
+#[test]
+fn commitment_does_not_leak_vote() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        assert_ok!(VotingModule::commit_vote(
+            origin,
+            proposal_hash,
+            commitment,
+            8
+        ));
+
+        // the salt is not part of the on-chain state
+        let commit = <Commits<Test>>::get(&alice, proposal_hash).unwrap();
+        let encoded = commit.encode();
+        assert!(!encoded.windows(salt.len()).any(|window| window == salt));
+
+        // without the salt, trying every option against public data does not
+        // reproduce the commitment
+        for option in [HELPFUL, SOMEWHAT_HELPFUL, NOT_HELPFUL] {
+            let guess = VotingModule::commitment_of(&option, &[0u8; 32], &alice, &proposal_hash);
+            assert_ne!(guess, commit.commitment);
+            let guess = BlakeTwo256::hash_of(&(option, &alice, proposal_hash));
+            assert_ne!(guess, commit.commitment);
+        }
+    });
+}
+
+#[test]
+fn cannot_reveal_with_wrong_salt() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, _) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 8);
+        System::set_block_number(<ProposalData<Test>>::get(proposal_hash).unwrap().poll_end);
+        assert_ok!(VotingModule::close_vote(origin.clone(), proposal_hash));
+
+        let result = VotingModule::reveal_vote(origin, proposal_hash, HELPFUL, [1u8; 32]);
+        assert_noop!(result, Error::<Test>::CommitmentMismatch);
+    });
+}
+
+#[test]
+fn commitment_is_bound_to_voter() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin_alice = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin_alice.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin_alice.clone());
+
+        let origin_bob = RuntimeOrigin::signed(get_bob());
+        let _ = Identity::set_identity(origin_bob.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin_bob.clone());
+
+        let _ = VotingModule::create_proposal(
+            origin_alice.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        // bob copies the commitment of alice
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin_alice, proposal_hash, commitment, 8);
+        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, commitment, 8);
+        System::set_block_number(<ProposalData<Test>>::get(proposal_hash).unwrap().poll_end);
+        assert_ok!(VotingModule::close_vote(origin_bob.clone(), proposal_hash));
+
+        let result = VotingModule::reveal_vote(origin_bob, proposal_hash, HELPFUL, salt);
+        assert_noop!(result, Error::<Test>::CommitmentMismatch);
+    });
+}
+
+#[test]
+fn migrate_to_v1_refunds_old_commits() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin,
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        // simulate a commit and a member written with the old layouts
+        StorageVersion::new(0).put::<VotingModule>();
+        let member_key = <Members<Test>>::hashed_key_for(&alice);
+        let old_member = v6::OldVoterBalance {
+            voting_tokens: 100 - 8u8.pow(2),
+            reserved_balance: EntryFee::get(),
+        };
+        frame_support::storage::unhashed::put(&member_key, &old_member);
+        let old = v1::OldCommit {
+            signature: MultiSignature::Sr25519(sp_core::sr25519::Signature::from_raw([0u8; 64])),
+            number: 8,
+            salt: 10u32,
+        };
+        let key = <Commits<Test>>::hashed_key_for(&alice, proposal_hash);
+        frame_support::storage::unhashed::put(&key, &old);
+
+        v1::MigrateToV1::<Test, MultiSignature>::on_runtime_upgrade();
+
+        assert!(<Commits<Test>>::get(&alice, proposal_hash).is_none());
+        let member: v6::OldVoterBalance<Balance> =
+            frame_support::storage::unhashed::get(&member_key).unwrap();
+        assert_eq!(member.voting_tokens, 100);
+        assert_eq!(StorageVersion::get::<VotingModule>(), 1);
+    });
+}
+
+#[test]
+fn commit_phase_closes_automatically() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice);
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        run_to_block(100);
+        assert!(<ProposalData<Test>>::get(proposal_hash).unwrap().reveal_end.is_none());
+
+        run_to_block(101);
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal.reveal_end, Some(151));
+        System::assert_last_event(Event::<Test>::ClosedCommit(proposal_hash).into());
+
+        // the manual fallback has nothing left to do
+        assert_noop!(
+            VotingModule::close_vote(origin, proposal_hash),
+            Error::<Test>::VoteAlreadyEnded
+        );
+    });
+}
+
+#[test]
+fn reveal_phase_closes_automatically() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 8);
+
+        run_to_block(101);
+        assert_ok!(VotingModule::reveal_vote(
+            origin,
+            proposal_hash,
+            HELPFUL,
+            salt
+        ));
+
+        run_to_block(151);
+        assert!(<ProposalResults<Test>>::contains_key(proposal_hash));
+        assert!(<Deadlines<Test>>::get(151).is_empty());
+        System::assert_has_event(
+            Event::<Test>::ClosedReveal {
+                proposal_hash,
+                tally: vec![8, 0, 0].try_into().unwrap(),
+                breakdown: vec![Permill::one(), Permill::zero(), Permill::zero()]
+                    .try_into()
+                    .unwrap(),
+                winners: vec![HELPFUL].try_into().unwrap(),
+                revealed: 1,
+                payout: 0,
+            }
+            .into(),
+        );
+        assert_eq!(<Members<Test>>::get(alice).voting_tokens, MaxTokens::get());
+    });
+}
+
+#[test]
+fn deadline_skips_manually_closed_proposal() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice);
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        // the hook of block 100 did not run, the proposal is closed by hand
+        System::set_block_number(110);
+        assert_ok!(VotingModule::close_vote(origin, proposal_hash));
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal.reveal_end, Some(160));
+
+        VotingModule::on_initialize(100);
+        assert_eq!(<ProposalData<Test>>::get(proposal_hash).unwrap(), proposal);
+        assert_eq!(<Deadlines<Test>>::get(160).len(), 1);
+    });
+}
+
+#[test]
+fn closed_proposal_is_archived() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 8);
+        run_to_block(101);
+        let _ = VotingModule::reveal_vote(origin.clone(), proposal_hash, HELPFUL, salt);
+        run_to_block(151);
+
+        assert!(<Proposals<Test>>::get().is_empty());
+        assert!(<ProposalData<Test>>::get(proposal_hash).is_none());
+        assert_eq!(
+            <ProposalResults<Test>>::get(proposal_hash),
+            Some(ProposalResult {
+                outcome: Outcome::Approved,
+                winners: vec![HELPFUL].try_into().unwrap(),
+                tally: vec![8, 0, 0].try_into().unwrap(),
+                breakdown: vec![Permill::one(), Permill::zero(), Permill::zero()]
+                    .try_into()
+                    .unwrap(),
+                payout: 0,
+                closed_at: 151,
+            })
+        );
+
+        // the same note cannot be proposed again while its outcome is retained
+        let result = VotingModule::create_proposal(
+            origin,
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        assert_noop!(result, Error::<Test>::DuplicateProposal);
+    });
+}
+
+#[test]
+fn closed_proposals_free_their_slot() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice);
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+
+        for i in 0..MaxProposals::get() as u8 {
+            assert_ok!(VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None
+            ));
+        }
+        let result = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(10)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        assert_noop!(result, Error::<Test>::TooManyProposals);
+
+        // the phases are closed `MaxClosesPerBlock` at a time
+        let blocks = MaxProposals::get().div_ceil(MaxClosesPerBlock::get()) as u64;
+        run_to_block(150 + blocks - 1);
+        assert!(<Proposals<Test>>::get().is_empty());
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(10)),
+            target(),
+            100,
+            None,
+            None
+        ));
+    });
+}
+
+#[test]
+fn result_pruned_after_retention() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice);
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        run_to_block(151);
+        assert_noop!(
+            VotingModule::prune_result(origin, proposal_hash),
+            Error::<Test>::RetentionNotExpired
+        );
+
+        run_to_block(250);
+        assert!(<ProposalResults<Test>>::contains_key(proposal_hash));
+        run_to_block(251);
+        assert!(!<ProposalResults<Test>>::contains_key(proposal_hash));
+        System::assert_last_event(Event::<Test>::ResultPruned(proposal_hash).into());
+    });
+}
+
+#[test]
+fn prune_result_manually() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice);
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        System::set_block_number(110);
+        let _ = VotingModule::close_vote(origin.clone(), proposal_hash);
+        System::set_block_number(160);
+        let _ = VotingModule::close_reveal(origin.clone(), proposal_hash);
+
+        // the automatic pruning at block 260 never runs
+        System::set_block_number(300);
+        let bob = RuntimeOrigin::signed(get_bob());
+        assert_ok!(VotingModule::prune_result(bob.clone(), proposal_hash));
+        assert_noop!(
+            VotingModule::prune_result(bob, proposal_hash),
+            Error::<Test>::ResultMissing
+        );
+    });
+}
+
+#[test]
+fn reveal_after_archive_is_late() {
+    new_test_ext().execute_with(|| {
+        let voters = [get_alice(), get_bob(), get_dave()];
+        for voter in voters.iter() {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(get_alice()),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        for voter in voters.iter() {
+            let (commitment, _) = generate(voter, proposal_hash, NOT_HELPFUL);
+            let _ = VotingModule::commit_vote(
+                RuntimeOrigin::signed(voter.clone()),
+                proposal_hash,
+                commitment,
+                2,
+            );
+        }
+
+        // one commit is left over after reaping `ReapLimit` of them on close
+        run_to_block(151);
+        assert!(<ProposalData<Test>>::get(proposal_hash).is_none());
+        let late = <Committers<Test>>::iter_key_prefix(proposal_hash).next().unwrap();
+
+        let reserved = <Members<Test>>::get(&late).reserved_balance;
+        let (_, salt) = generate(&late, proposal_hash, NOT_HELPFUL);
+        assert_ok!(VotingModule::reveal_vote(
+            RuntimeOrigin::signed(late.clone()),
+            proposal_hash,
+            NOT_HELPFUL,
+            salt
+        ));
+        assert!(<Commits<Test>>::get(&late, proposal_hash).is_none());
+        assert!(<Committers<Test>>::iter_prefix(proposal_hash).next().is_none());
+        assert_eq!(
+            <Members<Test>>::get(&late).reserved_balance,
+            reserved - reserved / 10
+        );
+        assert_eq!(<Members<Test>>::get(&late).voting_tokens, MaxTokens::get());
+    });
+}
+
+#[test]
+fn migrate_to_v2_unsticks_proposals() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+
+        for i in 0..MaxProposals::get() as u8 {
+            let _ = VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None,
+            );
+        }
+        let proposals = <Proposals<Test>>::get();
+
+        // simulate proposals closed before they were archived on close
+        for (i, proposal_hash) in proposals.iter().enumerate() {
+            let closed = i > 0;
+            put_v3_proposal(proposal_hash, 0, 0, closed);
+        }
+        StorageVersion::new(1).put::<VotingModule>();
+        System::set_block_number(200);
+
+        v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        v3::MigrateToV3::<Test>::on_runtime_upgrade();
+        v4::MigrateToV4::<Test>::on_runtime_upgrade();
+        v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+        assert_eq!(<Proposals<Test>>::get().into_inner(), vec![proposals[0]]);
+        for proposal_hash in proposals.iter().skip(1) {
+            assert!(<ProposalData<Test>>::get(proposal_hash).is_none());
+            let result = <ProposalResults<Test>>::get(proposal_hash).unwrap();
+            assert_eq!(result.outcome, Outcome::Tie);
+            assert_eq!(result.closed_at, 200);
+        }
+        assert_eq!(StorageVersion::get::<VotingModule>(), 5);
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(10)),
+            target(),
+            100,
+            None,
+            None
+        ));
+    });
+}
+
+#[test]
+fn unrevealed_commits_reaped_on_close() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin_alice = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin_alice.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin_alice.clone());
+
+        let bob = get_bob();
+        let origin_bob = RuntimeOrigin::signed(bob.clone());
+        let _ = Identity::set_identity(origin_bob.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin_bob.clone());
+
+        let _ = VotingModule::create_proposal(
+            origin_alice.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin_alice.clone(), proposal_hash, commitment, 8);
+        let (commitment, _) = generate(&bob, proposal_hash, NOT_HELPFUL);
+        let _ = VotingModule::commit_vote(origin_bob.clone(), proposal_hash, commitment, 2);
+
+        run_to_block(101);
+        let _ = VotingModule::reveal_vote(origin_alice, proposal_hash, HELPFUL, salt);
+        assert_eq!(<ProposalData<Test>>::get(proposal_hash).unwrap().voters, 2);
+        assert!(<Votes<Test>>::contains_key(proposal_hash, &alice));
+        assert_noop!(
+            VotingModule::leave_committee(origin_bob.clone()),
+            Error::<Test>::InMotion
+        );
+
+        let reserved = <Members<Test>>::get(&bob).reserved_balance;
+        run_to_block(151);
+
+        let slashed = NonRevealPenalty::get().mul_floor(reserved);
+        System::assert_last_event(
+            Event::<Test>::Reaped {
+                account: bob.clone(),
+                proposal_hash,
+                slashed,
+            }
+            .into(),
+        );
+        assert!(<Commits<Test>>::get(&bob, proposal_hash).is_none());
+        assert!(<Committers<Test>>::iter_prefix(proposal_hash).next().is_none());
+        assert!(<Votes<Test>>::iter_prefix(proposal_hash).next().is_none());
+        assert_eq!(
+            <Members<Test>>::get(&bob).reserved_balance,
+            reserved - slashed
+        );
+        assert_eq!(<Members<Test>>::get(&bob).voting_tokens, MaxTokens::get());
+        assert_ok!(VotingModule::leave_committee(origin_bob));
+    });
+}
+
+#[test]
+fn reap_unrevealed_in_pages() {
+    new_test_ext().execute_with(|| {
+        let voters = [get_alice(), get_bob(), get_dave()];
+        for voter in voters.iter() {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(get_alice());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        for voter in voters.iter() {
+            let (commitment, _) = generate(voter, proposal_hash, HELPFUL);
+            let _ = VotingModule::commit_vote(
+                RuntimeOrigin::signed(voter.clone()),
+                proposal_hash,
+                commitment,
+                1,
+            );
+        }
+        assert_noop!(
+            VotingModule::reap_unrevealed(origin.clone(), proposal_hash, 1),
+            Error::<Test>::NotFinished
+        );
+
+        run_to_block(150);
+        // only `ReapLimit` commits are reaped on close
+        assert_eq!(<Committers<Test>>::iter_prefix(proposal_hash).count(), 1);
+
+        assert_noop!(
+            VotingModule::reap_unrevealed(origin.clone(), proposal_hash, ReapLimit::get() + 1),
+            Error::<Test>::InvalidArgument
+        );
+        assert_ok!(VotingModule::reap_unrevealed(
+            origin.clone(),
+            proposal_hash,
+            ReapLimit::get()
+        ));
+        for voter in voters.iter() {
+            assert!(<Commits<Test>>::get(voter, proposal_hash).is_none());
+        }
+        assert_noop!(
+            VotingModule::reap_unrevealed(origin, proposal_hash, 1),
+            Error::<Test>::NoCommit
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v3_indexes_commits() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(Data::Raw(BoundedVec::default())),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, _) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin, proposal_hash, commitment, 1);
+
+        // simulate a commit made before the index existed
+        <Committers<Test>>::remove(proposal_hash, &alice);
+        StorageVersion::new(2).put::<VotingModule>();
+
+        v3::MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert!(<Committers<Test>>::contains_key(proposal_hash, &alice));
+        assert_eq!(StorageVersion::get::<VotingModule>(), 3);
+    });
+}
+
+#[test]
+fn ratings_with_three_options() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let voters = [get_alice(), get_bob(), get_dave()];
+        for voter in voters.iter() {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(get_alice());
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None
+        ));
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal.options, types::default_options());
+        assert_eq!(proposal.tally.into_inner(), vec![0, 0, 0]);
+
+        let choices = [(HELPFUL, 5), (SOMEWHAT_HELPFUL, 2), (NOT_HELPFUL, 3)];
+        for (voter, (option, number)) in voters.iter().zip(choices) {
+            let (commitment, _) = generate(voter, proposal_hash, option);
+            assert_ok!(VotingModule::commit_vote(
+                RuntimeOrigin::signed(voter.clone()),
+                proposal_hash,
+                commitment,
+                number
+            ));
+        }
+        run_to_block(101);
+        for (voter, (option, _)) in voters.iter().zip(choices) {
+            assert_ok!(VotingModule::reveal_vote(
+                RuntimeOrigin::signed(voter.clone()),
+                proposal_hash,
+                option,
+                SALT
+            ));
+        }
+        let bob_reserved = <Members<Test>>::get(get_bob()).reserved_balance;
+        let dave_reserved = <Members<Test>>::get(get_dave()).reserved_balance;
+        run_to_block(151);
+
+        let result = <ProposalResults<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(result.outcome, Outcome::Approved);
+        assert_eq!(result.winners.into_inner(), vec![HELPFUL]);
+        assert_eq!(result.tally.into_inner(), vec![5, 2, 3]);
+        assert_eq!(
+            result.breakdown.into_inner(),
+            vec![
+                Permill::from_percent(50),
+                Permill::from_percent(20),
+                Permill::from_percent(30)
+            ]
+        );
+        // every option other than the winning one loses
+        assert_eq!(result.payout, bob_reserved / 10 + dave_reserved / 10);
+        System::assert_has_event(Event::<Test>::Approved(proposal_hash).into());
+    });
+}
+
+#[test]
+fn ratings_tie_between_options() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let voters = [get_alice(), get_bob()];
+        for voter in voters.iter() {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(get_alice());
+        let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+        let proposal_hash = <Proposals<Test>>::get()[0];
+
+        let choices = [SOMEWHAT_HELPFUL, NOT_HELPFUL];
+        for (voter, option) in voters.iter().zip(choices) {
+            let (commitment, _) = generate(voter, proposal_hash, option);
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = VotingModule::commit_vote(origin, proposal_hash, commitment, 2);
+        }
+        run_to_block(101);
+        for (voter, option) in voters.iter().zip(choices) {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            assert_ok!(VotingModule::reveal_vote(
+                origin,
+                proposal_hash,
+                option,
+                SALT
+            ));
+        }
+        run_to_block(151);
+
+        let result = <ProposalResults<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(result.outcome, Outcome::Tie);
+        assert_eq!(
+            result.winners.into_inner(),
+            vec![SOMEWHAT_HELPFUL, NOT_HELPFUL]
+        );
+        System::assert_has_event(Event::<Test>::Tie(proposal_hash).into());
+    });
+}
+
+#[test]
+fn custom_rating_options() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+
+        let options = |labels: &[&[u8]]| -> RatingOptions {
+            labels
+                .iter()
+                .map(|label| RatingLabel::truncate_from(label.to_vec()))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap()
+        };
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(1)),
+                target(),
+                100,
+                Some(options(&[b"Helpful"])),
+                None
+            ),
+            Error::<Test>::TooFewOptions
+        );
+        assert_ok!(VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            Some(options(&[b"Accurate", b"Misleading"])),
+            None
+        ));
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        assert_eq!(
+            <ProposalData<Test>>::get(proposal_hash).unwrap().tally.len(),
+            2
+        );
+
+        // an option the proposal does not have cannot be revealed
+        let (commitment, salt) = generate(&alice, proposal_hash, 2);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 1);
+        run_to_block(101);
+        assert_noop!(
+            VotingModule::reveal_vote(origin, proposal_hash, 2, salt),
+            Error::<Test>::InvalidOption
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v4_tallies_options() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        for i in 0..2 {
+            let _ = VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None,
+            );
+        }
+        let proposals = <Proposals<Test>>::get();
+
+        // a pending yes commitment stays valid as the first option
+        let (commitment, salt) = generate(&alice, proposals[0], 0);
+        let _ = VotingModule::commit_vote(origin.clone(), proposals[0], commitment, 1);
+        put_v3_proposal(&proposals[0], 8, 3, false);
+        <ProposalData<Test>>::remove(proposals[1]);
+        let old_result = v4::OldProposalResult {
+            outcome: Outcome::Disapproved,
+            ayes: 1,
+            nays: 3,
+            payout: 7u128,
+            closed_at: 5u64,
+        };
+        frame_support::storage::unhashed::put(
+            &<ProposalResults<Test>>::hashed_key_for(proposals[1]),
+            &old_result,
+        );
+        StorageVersion::new(3).put::<VotingModule>();
+
+        v4::MigrateToV4::<Test>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get::<VotingModule>(), 4);
+        v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+        let proposal = <ProposalData<Test>>::get(proposals[0]).unwrap();
+        assert_eq!(proposal.options, v4::yes_no_options());
+        assert_eq!(proposal.tally.into_inner(), vec![8, 3]);
+        let result = <ProposalResults<Test>>::get(proposals[1]).unwrap();
+        assert_eq!(result.outcome, Outcome::Disapproved);
+        assert_eq!(result.winners.into_inner(), vec![1]);
+        assert_eq!(result.tally.into_inner(), vec![1, 3]);
+        assert_eq!(
+            result.breakdown.into_inner(),
+            vec![Permill::from_percent(25), Permill::from_percent(75)]
+        );
+
+        run_to_block(101);
+        assert_ok!(VotingModule::reveal_vote(origin, proposals[0], 0, salt));
+    });
+}
+
+#[test]
+fn queries_active_proposals_and_members() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 3);
+
+        let proposals = VotingModule::active_proposals();
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].proposal_hash, proposal_hash);
+        assert_eq!(proposals[0].phase, Phase::Commit);
+        assert_eq!(proposals[0].poll_end, 101);
+        // nothing is disclosed before the reveal phase
+        assert_eq!(proposals[0].tally, None);
+        assert_eq!(
+            VotingModule::pending_commits(&alice),
+            vec![(proposal_hash, 3)]
+        );
+        assert_eq!(
+            VotingModule::member(&alice),
+            Some(<Members<Test>>::get(&alice))
+        );
+        assert_eq!(VotingModule::member(&get_bob()), None);
+
+        run_to_block(101);
+        let _ = VotingModule::reveal_vote(origin, proposal_hash, HELPFUL, salt);
+        let proposal = VotingModule::proposal_info(proposal_hash).unwrap();
+        assert_eq!(proposal.phase, Phase::Reveal);
+        assert_eq!(proposal.reveal_end, Some(151));
+        assert_eq!(proposal.tally.unwrap().into_inner(), vec![3, 0, 0]);
+        assert_eq!(proposal.revealed, 1);
+        assert!(VotingModule::pending_commits(&alice).is_empty());
+
+        run_to_block(151);
+        assert!(VotingModule::active_proposals().is_empty());
+        assert_eq!(VotingModule::proposal_info(proposal_hash), None);
+    });
+}
+
+parameter_types! {
+    pub const PerVote: Perbill = Perbill::from_percent(5);
+    pub const SlashCap: u128 = 50;
+}
+
+#[test]
+fn percentage_slash_policy() {
+    assert_eq!(Percentage::<SideSlash>::slash(1_000u128, 1), 100);
+    assert_eq!(Percentage::<SideSlash>::slash(1_000u128, 9), 100);
+    assert_eq!(Percentage::<SideSlash>::slash(0u128, 9), 0);
+}
+
+#[test]
+fn stake_weighted_slash_policy() {
+    assert_eq!(StakeWeighted::<PerVote>::slash(1_000u128, 1), 50);
+    assert_eq!(StakeWeighted::<PerVote>::slash(1_000u128, 4), 200);
+    // never more than the reserved funds
+    assert_eq!(StakeWeighted::<PerVote>::slash(1_000u128, 30), 1_000);
+}
+
+#[test]
+fn capped_slash_policy() {
+    assert_eq!(
+        Capped::<Percentage<SideSlash>, SlashCap>::slash(300u128, 1),
+        30
+    );
+    assert_eq!(
+        Capped::<Percentage<SideSlash>, SlashCap>::slash(1_000u128, 1),
+        50
+    );
+    assert_eq!(
+        Capped::<StakeWeighted<PerVote>, SlashCap>::slash(1_000u128, 4),
+        50
+    );
+}
+
+#[test]
+fn rewards_are_pro_rata_to_votes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let voters = [
+            (get_alice(), 3, HELPFUL),
+            (get_bob(), 1, HELPFUL),
+            (get_dave(), 2, NOT_HELPFUL),
+        ];
+        for (who, _, _) in voters.iter() {
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(get_alice());
+        let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        for (who, number, option) in voters.iter() {
+            let (commitment, _) = generate(who, proposal_hash, *option);
+            let origin = RuntimeOrigin::signed(who.clone());
+            assert_ok!(VotingModule::commit_vote(
+                origin,
+                proposal_hash,
+                commitment,
+                *number
+            ));
+        }
+        run_to_block(101);
+        for (who, _, option) in voters.iter() {
+            let origin = RuntimeOrigin::signed(who.clone());
+            assert_ok!(VotingModule::reveal_vote(
+                origin,
+                proposal_hash,
+                *option,
+                SALT
+            ));
+        }
+        run_to_block(151);
+
+        let deposit = EntryFee::get();
+        let slash = SideSlash::get().mul_floor(deposit);
+        let reserved = |who: &AccountId| <Members<Test>>::get(who).reserved_balance;
+        assert_eq!(reserved(&get_dave()), deposit - slash);
+        assert_eq!(reserved(&get_alice()), deposit + slash * 3 / 4);
+        assert_eq!(reserved(&get_bob()), deposit + slash / 4);
+    });
+}
+
+#[test]
+fn estimated_payout_matches_close() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let voters = [get_alice(), get_bob(), get_dave()];
+        for voter in voters.iter() {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(get_alice());
+        let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let choices = [(HELPFUL, 3), (NOT_HELPFUL, 1), (NOT_HELPFUL, 1)];
+        for (voter, (option, number)) in voters.iter().zip(choices) {
+            let (commitment, _) = generate(voter, proposal_hash, option);
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = VotingModule::commit_vote(origin, proposal_hash, commitment, number);
+        }
+        run_to_block(101);
+        // dave does not reveal
+        for (voter, (option, _)) in voters.iter().zip(choices).take(2) {
+            let origin = RuntimeOrigin::signed(voter.clone());
+            let _ = VotingModule::reveal_vote(origin, proposal_hash, option, SALT);
+        }
+
+        let reserved = |who: &AccountId| <Members<Test>>::get(who).reserved_balance;
+        let before: Vec<_> = voters.iter().map(reserved).collect();
+        let estimates: Vec<_> = voters
+            .iter()
+            .map(|voter| VotingModule::estimate_payout(voter, proposal_hash).unwrap())
+            .collect();
+        assert_eq!(estimates[0].slash, 0);
+        assert_eq!(estimates[1].reward, 0);
+        assert_eq!(
+            estimates[2].slash,
+            NonRevealPenalty::get().mul_floor(before[2])
+        );
+
+        run_to_block(151);
+        for (i, voter) in voters.iter().enumerate() {
+            assert_eq!(
+                reserved(voter),
+                before[i] + estimates[i].reward - estimates[i].slash
+            );
+        }
+        assert_eq!(
+            VotingModule::estimate_payout(&voters[0], proposal_hash),
+            None
+        );
+    });
+}
+
+/// Creates a proposal carrying `call` and approves it with a single vote.
+fn approve_call(call: RuntimeCall) -> H256 {
+    System::set_block_number(1);
+    let alice = get_alice();
+    let origin = RuntimeOrigin::signed(alice.clone());
+    let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+    let _ = VotingModule::join_committee(origin.clone());
+    assert_ok!(VotingModule::create_proposal(
+        origin.clone(),
+        Box::new(note(1)),
+        target(),
+        100,
+        None,
+        Some(Box::new(call))
+    ));
+    let proposal_hash = <Proposals<Test>>::get()[0];
+    assert!(<ProposalCalls<Test>>::contains_key(proposal_hash));
+    let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+    let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 1);
+    run_to_block(101);
+    let _ = VotingModule::reveal_vote(origin, proposal_hash, HELPFUL, salt);
+    run_to_block(151);
+    proposal_hash
+}
+
+#[test]
+fn closing_counts_the_weight_of_the_call() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        join(&alice);
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let call = RuntimeCall::Identity(pallet_identity::Call::add_registrar {
+            account: get_dave(),
+        });
+        let call_weight = call.get_dispatch_info().weight;
+        assert_ok!(VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            Some(Box::new(call))
+        ));
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, salt) = generate(&alice, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 1);
+        run_to_block(101);
+        let _ = VotingModule::reveal_vote(origin.clone(), proposal_hash, HELPFUL, salt);
+
+        // the declared weight budgets the heaviest call
+        let info = crate::Call::<Test>::close_reveal {
+            proposal: proposal_hash,
+        }
+        .get_dispatch_info();
+        assert!(info.weight.all_gte(MaxCallWeight::get()));
+
+        System::set_block_number(151);
+        let post = VotingModule::close_reveal(origin, proposal_hash).unwrap();
+        assert_eq!(Identity::registrars().len(), 1);
+        let closing = <() as crate::WeightInfo>::close_reveal(1)
+            .saturating_add(<() as crate::WeightInfo>::reap_unrevealed(0));
+        let actual = post.actual_weight.unwrap();
+        assert!(actual.ref_time() > closing.ref_time());
+        assert!(actual.all_lte(closing.saturating_add(call_weight)));
+    });
+}
+
+#[test]
+fn approved_proposal_dispatches_call() {
+    new_test_ext().execute_with(|| {
+        let dave = get_dave();
+        let call = RuntimeCall::Identity(pallet_identity::Call::add_registrar {
+            account: dave.clone(),
+        });
+        let proposal_hash = approve_call(call);
+
+        System::assert_has_event(Event::<Test>::CallDispatched { proposal_hash }.into());
+        assert_eq!(Identity::registrars()[0].as_ref().unwrap().account, dave);
+        assert!(!<ProposalCalls<Test>>::contains_key(proposal_hash));
+    });
+}
+
+#[test]
+fn failed_call_is_reported() {
+    new_test_ext().execute_with(|| {
+        // only signed origins can remark with an event
+        let call = RuntimeCall::System(frame_system::Call::remark_with_event {
+            remark: b"note".to_vec(),
+        });
+        let proposal_hash = approve_call(call);
+
+        System::assert_has_event(
+            Event::<Test>::CallFailed {
+                proposal_hash,
+                error: DispatchError::BadOrigin,
+            }
+            .into(),
+        );
+        assert!(<ProposalResults<Test>>::contains_key(proposal_hash));
+    });
+}
+
+#[test]
+fn rejected_proposal_drops_call() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let call = RuntimeCall::Identity(pallet_identity::Call::add_registrar {
+            account: get_dave(),
+        });
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            Some(Box::new(call)),
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, salt) = generate(&alice, proposal_hash, NOT_HELPFUL);
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 1);
+        run_to_block(101);
+        let _ = VotingModule::reveal_vote(origin, proposal_hash, NOT_HELPFUL, salt);
+        run_to_block(151);
+
+        assert!(!<ProposalCalls<Test>>::contains_key(proposal_hash));
+        assert!(Identity::registrars().is_empty());
+    });
+}
+
+#[test]
+fn proposal_call_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(get_alice());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+
+        let call = RuntimeCall::System(frame_system::Call::remark {
+            remark: vec![0u8; MaxCallLen::get() as usize],
+        });
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(1)),
+                target(),
+                100,
+                None,
+                Some(Box::new(call))
+            ),
+            Error::<Test>::CallTooLong
+        );
+
+        let call =
+            RuntimeCall::Identity(pallet_identity::Call::kill_identity { target: get_bob() });
+        assert!(!call.get_dispatch_info().weight.all_lte(MaxCallWeight::get()));
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin,
+                Box::new(note(1)),
+                target(),
+                100,
+                None,
+                Some(Box::new(call))
+            ),
+            Error::<Test>::CallTooHeavy
+        );
+    });
+}
+
+#[test]
+fn ensure_voting_approved() {
+    new_test_ext().execute_with(|| {
+        let proposal_hash = H256::repeat_byte(1);
+        let origin: RuntimeOrigin = crate::RawOrigin::Approved(proposal_hash).into();
+        assert_eq!(
+            EnsureVotingApproved::<H256>::try_origin(origin).ok(),
+            Some(proposal_hash)
+        );
+        assert!(
+            EnsureVotingApproved::<H256>::try_origin(RuntimeOrigin::signed(get_alice())).is_err()
+        );
+        assert!(EnsureVotingApproved::<H256>::try_origin(RuntimeOrigin::root()).is_err());
+    });
+}
+
+#[test]
+fn voters_per_proposal_are_bounded() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        <ProposalData<Test>>::mutate(proposal_hash, |proposal| {
+            proposal.as_mut().unwrap().voters = MaxVotersPerProposal::get();
+        });
+
+        let (commitment, _) = generate(&alice, proposal_hash, HELPFUL);
+        assert_noop!(
+            VotingModule::commit_vote(origin, proposal_hash, commitment, 1),
+            Error::<Test>::TooManyVoters
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v5_moves_votes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let bob = get_bob();
+        for who in [&alice, &bob] {
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, _) = generate(&bob, proposal_hash, NOT_HELPFUL);
+        let _ = VotingModule::commit_vote(
+            RuntimeOrigin::signed(bob.clone()),
+            proposal_hash,
+            commitment,
+            1,
+        );
+
+        // alice revealed before the upgrade, bob has not yet
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        let old = v5::OldProposal {
+            title: proposal.title,
+            proposer: proposal.proposer,
+            options: proposal.options,
+            tally: vec![2, 0, 0].try_into().unwrap(),
+            poll_end: proposal.poll_end,
+            reveal_end: Some(150u64),
+            votes: vec![(alice.clone(), 2, HELPFUL)],
+            revealed: vec![alice.clone()],
+            payout: proposal.payout,
+            closed: false,
+        };
+        frame_support::storage::unhashed::put(
+            &<ProposalData<Test>>::hashed_key_for(proposal_hash),
+            &old,
+        );
+        StorageVersion::new(4).put::<VotingModule>();
+
+        v5::MigrateToV5::<Test>::on_runtime_upgrade();
+
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal.voters, 2);
+        assert_eq!(proposal.revealed, 1);
+        // votes are written with their layout before v6
+        let vote: v6::OldVoteRecord = frame_support::storage::unhashed::get(
+            &<Votes<Test>>::hashed_key_for(proposal_hash, &alice),
+        )
+        .unwrap();
+        assert_eq!((vote.number, vote.option), (2, HELPFUL));
+        assert!(VotingModule::already_voted(&alice, &proposal_hash));
+        assert_eq!(StorageVersion::get::<VotingModule>(), 5);
+    });
+}
+
+#[test]
+fn migrate_to_v6_widens_credits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let bob = get_bob();
+        for who in [&alice, &bob] {
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, _) = generate(&bob, proposal_hash, NOT_HELPFUL);
+
+        // alice revealed 2 votes before the upgrade, bob committed 3
+        for (who, voting_tokens) in [(&alice, 96u8), (&bob, 91u8)] {
+            let old = v6::OldVoterBalance {
+                voting_tokens,
+                reserved_balance: EntryFee::get(),
+            };
+            frame_support::storage::unhashed::put(&<Members<Test>>::hashed_key_for(who), &old);
+        }
+        let old_commit = v6::OldCommit {
+            commitment,
+            number: 3,
+        };
+        frame_support::storage::unhashed::put(
+            &<Commits<Test>>::hashed_key_for(&bob, proposal_hash),
+            &old_commit,
+        );
+        let old_vote = v6::OldVoteRecord {
+            number: 2,
+            option: HELPFUL,
+        };
+        frame_support::storage::unhashed::put(
+            &<Votes<Test>>::hashed_key_for(proposal_hash, &alice),
+            &old_vote,
+        );
+        StorageVersion::new(5).put::<VotingModule>();
+
+        v6::MigrateToV6::<Test>::on_runtime_upgrade();
+
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, 96);
+        assert_eq!(<Members<Test>>::get(&bob).voting_tokens, 91);
+        assert_eq!(<Members<Test>>::count(), 2);
+        assert_eq!(<Commits<Test>>::get(&bob, proposal_hash).unwrap().number, 3);
+        assert_eq!(
+            <Votes<Test>>::get(proposal_hash, &alice),
+            Some(types::VoteRecord {
+                number: 2,
+                option: HELPFUL,
+            })
+        );
+        assert_eq!(StorageVersion::get::<VotingModule>(), 6);
+    });
+}
+
+#[test]
+fn quadratic_cost_is_bounded_by_credits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        for i in 0..3 {
+            let _ = VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None,
+            );
+        }
+        let proposals = <Proposals<Test>>::get();
+        let commit = |proposal_hash: H256, number: VoteCredit| {
+            let (commitment, _) = generate(&alice, proposal_hash, HELPFUL);
+            VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, number)
+        };
+
+        // 16 votes used to overflow a u8 cost
+        assert_noop!(
+            commit(proposals[0], 16),
+            Error::<Test>::NotEnoughVotingTokens
+        );
+        assert_noop!(
+            commit(proposals[0], 11),
+            Error::<Test>::NotEnoughVotingTokens
+        );
+        assert_noop!(commit(proposals[0], 1 << 16), Error::<Test>::CostOverflow);
+        assert_noop!(
+            commit(proposals[0], VoteCredit::MAX),
+            Error::<Test>::CostOverflow
+        );
+        assert_ok!(commit(proposals[0], 10));
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, 0);
+        assert_noop!(
+            commit(proposals[1], 1),
+            Error::<Test>::NotEnoughVotingTokens
+        );
+    });
+}
+
+#[test]
+fn deposited_credits_are_capped() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin);
+
+        VotingModule::deposit_votes(&alice, VoteCredit::MAX);
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, MaxTokens::get());
+        assert!(VotingModule::decrease_votes(&alice, MaxTokens::get()));
+        VotingModule::refund_votes(&alice, VoteCredit::MAX);
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, MaxTokens::get());
+    });
+}
+
+/// Makes the proposer and the voters members, creates a proposal and commits
+/// and reveals the votes. Returns the proposal hash.
+fn vote_on_proposal(
+    proposer: &AccountId,
+    voters: &[(AccountId, VoteCredit, types::OptionIndex)],
+) -> H256 {
+    System::set_block_number(1);
+    for who in voters.iter().map(|(who, _, _)| who).chain([proposer]) {
+        let origin = RuntimeOrigin::signed(who.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin);
+    }
+    let origin = RuntimeOrigin::signed(proposer.clone());
+    let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+    let proposal_hash = <Proposals<Test>>::get()[0];
+    for (who, number, option) in voters.iter() {
+        let (commitment, _) = generate(who, proposal_hash, *option);
+        let origin = RuntimeOrigin::signed(who.clone());
+        assert_ok!(VotingModule::commit_vote(
+            origin,
+            proposal_hash,
+            commitment,
+            *number
+        ));
+    }
+    run_to_block(101);
+    for (who, _, option) in voters.iter() {
+        let origin = RuntimeOrigin::signed(who.clone());
+        assert_ok!(VotingModule::reveal_vote(
+            origin,
+            proposal_hash,
+            *option,
+            SALT
+        ));
+    }
+    proposal_hash
+}
+
+#[test]
+fn pot_keeps_the_dust_of_rewards() {
+    new_test_ext().execute_with(|| {
+        let proposal_hash = vote_on_proposal(
+            &get_alice(),
+            &[
+                (get_alice(), 3, HELPFUL),
+                (get_bob(), 4, HELPFUL),
+                (get_dave(), 2, NOT_HELPFUL),
+            ],
+        );
+        run_to_block(151);
+
+        let slash = SideSlash::get().mul_floor(EntryFee::get());
+        let paid = slash * 3 / 7 + slash * 4 / 7;
+        assert!(paid < slash);
+        assert_eq!(
+            <Pot<Test>>::get(),
+            PotLedger {
+                inflow: slash,
+                distributed: paid,
+                dust: slash - paid,
+                ..Default::default()
+            }
+        );
+        assert_eq!(<PotInflows<Test>>::get(proposal_hash), slash);
+        assert_eq!(
+            Balances::reserved_balance(VotingModule::account_id()),
+            slash - paid
+        );
+
+        // the inflows of a proposal are dropped with its outcome
+        run_to_block(151 + ResultRetention::get());
+        assert!(!<PotInflows<Test>>::contains_key(proposal_hash));
+    });
+}
+
+#[test]
+fn late_reveal_slash_is_unclaimed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let bob = get_bob();
+        for who in [&alice, &bob] {
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, _) = generate(&bob, proposal_hash, HELPFUL);
+        let origin = RuntimeOrigin::signed(bob.clone());
+        let _ = VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 1);
+        run_to_block(101);
+        System::set_block_number(152);
+
+        assert_ok!(VotingModule::reveal_vote(
+            origin,
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+
+        let slash = SideSlash::get().mul_floor(EntryFee::get());
+        assert_eq!(
+            <Pot<Test>>::get(),
+            PotLedger {
+                inflow: slash,
+                unclaimed: slash,
+                ..Default::default()
+            }
+        );
+        assert_eq!(<PotInflows<Test>>::get(proposal_hash), slash);
+    });
+}
+
+#[test]
+fn reputation_follows_the_outcome() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        vote_on_proposal(
+            &alice,
+            &[
+                (alice.clone(), 3, HELPFUL),
+                (bob.clone(), 4, HELPFUL),
+                (dave.clone(), 2, NOT_HELPFUL),
+            ],
+        );
+        run_to_block(151);
+
+        let pot = <Pot<Test>>::get();
+        let dave_reputation = VotingModule::reputation(&dave);
+        assert_eq!(
+            dave_reputation,
+            types::Reputation {
+                participated: 1,
+                slashed: pot.inflow,
+                ..Default::default()
+            }
+        );
+        System::assert_has_event(
+            Event::<Test>::ReputationUpdated {
+                account: dave,
+                reputation: dave_reputation,
+            }
+            .into(),
+        );
+        for who in [&alice, &bob] {
+            let reputation = VotingModule::reputation(who);
+            assert_eq!((reputation.participated, reputation.aligned), (1, 1));
+            assert_eq!(reputation.slashed, 0);
+        }
+        assert_eq!(
+            VotingModule::reputation(&alice).rewarded + VotingModule::reputation(&bob).rewarded,
+            pot.distributed
+        );
+    });
+}
+
+#[test]
+fn reputation_counts_late_and_missing_reveals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+            let _ = VotingModule::join_committee(origin);
+        }
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let _ = VotingModule::create_proposal(origin, Box::new(note(1)), target(), 100, None, None);
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        for who in [&bob, &dave] {
+            let (commitment, _) = generate(who, proposal_hash, HELPFUL);
+            let origin = RuntimeOrigin::signed(who.clone());
+            assert_ok!(VotingModule::commit_vote(
+                origin,
+                proposal_hash,
+                commitment,
+                1
+            ));
+        }
+        run_to_block(101);
+        System::set_block_number(152);
+
+        // bob reveals after the reveal phase, dave is reaped when it closes
+        assert_ok!(VotingModule::reveal_vote(
+            RuntimeOrigin::signed(bob.clone()),
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+        assert_ok!(VotingModule::close_reveal(
+            RuntimeOrigin::signed(alice.clone()),
+            proposal_hash
+        ));
+
+        let late = VotingModule::reputation(&bob);
+        assert_eq!((late.late_reveals, late.participated), (1, 0));
+        assert_eq!(late.slashed, SideSlash::get().mul_floor(EntryFee::get()));
+        let reaped = VotingModule::reputation(&dave);
+        assert_eq!((reaped.unrevealed, reaped.participated), (1, 0));
+        assert_eq!(
+            reaped.slashed,
+            NonRevealPenalty::get().mul_floor(EntryFee::get())
+        );
+        assert_eq!(VotingModule::reputation(&alice), Default::default());
+    });
+}
+
+#[test]
+fn bridging_outcome_rule() {
+    type Rule = Bridging<BridgingSupport, MinClusters>;
+    new_test_ext().execute_with(|| {
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        <Viewpoints<Test>>::insert(&alice, 0);
+        <Viewpoints<Test>>::insert(&bob, 1);
+        <Viewpoints<Test>>::insert(&dave, 1);
+        let mut votes = vec![
+            (
+                alice.clone(),
+                VoteRecord {
+                    number: 3,
+                    option: HELPFUL,
+                },
+            ),
+            (
+                bob,
+                VoteRecord {
+                    number: 2,
+                    option: HELPFUL,
+                },
+            ),
+            (
+                dave,
+                VoteRecord {
+                    number: 2,
+                    option: NOT_HELPFUL,
+                },
+            ),
+        ];
+
+        // helpful leads, but only half of the second cluster supports it
+        assert!(<Rule as OutcomeRule<Test>>::winners(&[5, 0, 2], &votes).is_empty());
+        assert_eq!(
+            <Rule as OutcomeRule<Test>>::outcome(&[]),
+            Outcome::Disapproved
+        );
+        votes[1].1.number = 3;
+        assert_eq!(
+            <Rule as OutcomeRule<Test>>::winners(&[6, 0, 2], &votes).into_inner(),
+            vec![HELPFUL]
+        );
+        // agreement within a single cluster is not enough
+        <Viewpoints<Test>>::insert(&alice, 1);
+        assert!(<Rule as OutcomeRule<Test>>::winners(&[6, 0, 2], &votes).is_empty());
+        // another option can still win on its own
+        assert_eq!(
+            <Rule as OutcomeRule<Test>>::winners(&[2, 0, 6], &votes).into_inner(),
+            vec![NOT_HELPFUL]
+        );
+        assert!(<Rule as OutcomeRule<Test>>::winners(&[0, 3, 3], &votes).is_empty());
+        assert_eq!(
+            <Majority as OutcomeRule<Test>>::winners(&[6, 0, 2], &votes).into_inner(),
+            vec![HELPFUL]
+        );
+        // voters without a viewpoint do not form a cluster
+        <Viewpoints<Test>>::remove(&alice);
+        votes.truncate(1);
+        assert!(<Rule as OutcomeRule<Test>>::winners(&[3, 0, 0], &votes).is_empty());
+    });
+}
+
+#[test]
+fn bridged_outcome_needs_every_viewpoint() {
+    new_test_ext().execute_with(|| {
+        Bridged::set(true);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        <Viewpoints<Test>>::insert(&alice, 0);
+        <Viewpoints<Test>>::insert(&bob, 1);
+        <Viewpoints<Test>>::insert(&dave, 1);
+        let proposal_hash = vote_on_proposal(
+            &alice,
+            &[
+                (alice.clone(), 3, HELPFUL),
+                (bob.clone(), 2, HELPFUL),
+                (dave.clone(), 2, NOT_HELPFUL),
+            ],
+        );
+        assert_eq!(
+            VotingModule::estimate_payout(&dave, proposal_hash),
+            Some(PayoutEstimate::default())
+        );
+        run_to_block(151);
+
+        // not approved, and nobody is slashed for it
+        let result = <ProposalResults<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(result.outcome, Outcome::Disapproved);
+        assert!(result.winners.is_empty());
+        assert_eq!(result.tally.into_inner(), vec![5, 0, 2]);
+        System::assert_has_event(Event::<Test>::Disapproved(proposal_hash).into());
+        for who in [&alice, &bob, &dave] {
+            assert_eq!(<Members<Test>>::get(who).reserved_balance, EntryFee::get());
+        }
+    });
+}
+
+#[test]
+fn unanimous_unbridged_vote_slashes_nobody() {
+    new_test_ext().execute_with(|| {
+        Bridged::set(true);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        // nobody declared a viewpoint
+        let proposal_hash = vote_on_proposal(
+            &alice,
+            &[(bob.clone(), 2, HELPFUL), (dave.clone(), 3, HELPFUL)],
+        );
+        run_to_block(151);
+
+        let result = <ProposalResults<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(result.outcome, Outcome::Disapproved);
+        assert!(result.winners.is_empty());
+        for who in [&bob, &dave] {
+            assert_eq!(<Members<Test>>::get(who).reserved_balance, EntryFee::get());
+            assert_eq!(Balances::reserved_balance(who), EntryFee::get());
+        }
+    });
+}
+
+#[test]
+fn single_viewpoint_vote_is_not_bridged() {
+    new_test_ext().execute_with(|| {
+        Bridged::set(true);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            <Viewpoints<Test>>::insert(who, 1);
+        }
+        // helpful leads within the only cluster
+        let proposal_hash = vote_on_proposal(
+            &alice,
+            &[
+                (alice.clone(), 3, HELPFUL),
+                (bob.clone(), 2, HELPFUL),
+                (dave.clone(), 2, NOT_HELPFUL),
+            ],
+        );
+        run_to_block(151);
+        let result = <ProposalResults<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(result.outcome, Outcome::Disapproved);
+        assert!(result.winners.is_empty());
+        assert_eq!(
+            <Members<Test>>::get(&dave).reserved_balance,
+            EntryFee::get()
+        );
+    });
+}
+
+#[test]
+fn members_declare_their_viewpoint() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob) = (get_alice(), get_bob());
+        assert_noop!(
+            VotingModule::declare_viewpoint(RuntimeOrigin::signed(bob.clone()), 1),
+            Error::<Test>::NotMember
+        );
+        let proposal_hash = vote_on_proposal(&alice, &[(bob.clone(), 2, HELPFUL)]);
+        let origin = RuntimeOrigin::signed(bob.clone());
+        assert_noop!(
+            VotingModule::declare_viewpoint(origin.clone(), 1),
+            Error::<Test>::InMotion
+        );
+        assert_noop!(
+            VotingModule::set_viewpoint(RuntimeOrigin::root(), bob.clone(), Some(1)),
+            Error::<Test>::InMotion
+        );
+        run_to_block(151);
+        assert!(<ProposalResults<Test>>::contains_key(proposal_hash));
+
+        assert_noop!(
+            VotingModule::declare_viewpoint(origin.clone(), MAX_VIEWPOINTS),
+            Error::<Test>::InvalidViewpoint
+        );
+        assert_ok!(VotingModule::declare_viewpoint(origin.clone(), 1));
+        assert_eq!(<Viewpoints<Test>>::get(&bob), Some(1));
+        System::assert_last_event(
+            Event::<Test>::ViewpointSet {
+                account: bob.clone(),
+                viewpoint: Some(1),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            VotingModule::set_viewpoint(origin.clone(), bob.clone(), Some(2)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(VotingModule::set_viewpoint(
+            RuntimeOrigin::root(),
+            bob.clone(),
+            Some(2)
+        ));
+        assert_eq!(<Viewpoints<Test>>::get(&bob), Some(2));
+
+        assert_ok!(VotingModule::leave_committee(origin));
+        assert!(!<Viewpoints<Test>>::contains_key(&bob));
+    });
+}
+
+#[test]
+fn viewpoint_changes_are_rate_limited() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let bob = get_bob();
+        join(&bob);
+        let origin = RuntimeOrigin::signed(bob.clone());
+        assert_ok!(VotingModule::declare_viewpoint(origin.clone(), 1));
+        assert_eq!(<ViewpointChanges<Test>>::get(&bob), Some(1));
+
+        System::set_block_number(ViewpointCooldown::get());
+        assert_noop!(
+            VotingModule::declare_viewpoint(origin.clone(), 2),
+            Error::<Test>::ViewpointCooldown
+        );
+        System::set_block_number(1 + ViewpointCooldown::get());
+        assert_ok!(VotingModule::declare_viewpoint(origin.clone(), 2));
+
+        // an assignment is not held by the cooldown, but restarts it
+        assert_ok!(VotingModule::set_viewpoint(
+            RuntimeOrigin::root(),
+            bob.clone(),
+            Some(3)
+        ));
+        assert_noop!(
+            VotingModule::declare_viewpoint(origin.clone(), 1),
+            Error::<Test>::ViewpointCooldown
+        );
+        assert_eq!(<Viewpoints<Test>>::get(&bob), Some(3));
+
+        assert_ok!(VotingModule::leave_committee(origin));
+        assert!(!<ViewpointChanges<Test>>::contains_key(&bob));
+    });
+}
+
+#[test]
+fn notes_are_indexed_by_target() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        let origin = RuntimeOrigin::signed(alice.clone());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        assert_ok!(VotingModule::join_committee(origin.clone()));
+
+        let mut empty = target();
+        empty.platform = Default::default();
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(1)),
+                empty,
+                100,
+                None,
+                None
+            ),
+            Error::<Test>::InvalidTarget
+        );
+
+        // the same post, seen at another time, and a post elsewhere
+        let mut later = target();
+        later.timestamp = Some(1_700_000_000);
+        let mut elsewhere = target();
+        elsewhere.platform = b"reddit.com".to_vec().try_into().unwrap();
+        for (i, target) in [(1, target()), (2, later.clone()), (3, elsewhere.clone())] {
+            assert_ok!(VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target,
+                100,
+                None,
+                None
+            ));
+        }
+        let first = BlakeTwo256::hash_of(&note(1));
+        let second = BlakeTwo256::hash_of(&note(2));
+        let third = BlakeTwo256::hash_of(&note(3));
+        System::assert_has_event(
+            Event::<Test>::Targeted {
+                proposal_hash: second,
+                target: later.clone(),
+            }
+            .into(),
+        );
+
+        let mut notes = VotingModule::notes_for_target(&later);
+        notes.sort();
+        let mut expected = vec![first, second];
+        expected.sort();
+        assert_eq!(notes, expected);
+        assert_eq!(VotingModule::notes_for_target(&elsewhere), vec![third]);
+        assert_eq!(
+            VotingModule::proposal_info(second).unwrap().target,
+            Some(later)
+        );
+
+        // closed notes stay listed until their outcome is pruned
+        run_to_block(151);
+        assert!(<ProposalResults<Test>>::contains_key(first));
+        assert_eq!(VotingModule::notes_for_target(&target()).len(), 2);
+        run_to_block(251);
+        assert!(!<ProposalResults<Test>>::contains_key(first));
+        assert!(VotingModule::notes_for_target(&target()).is_empty());
+        assert!(!<NoteTargets<Test>>::contains_key(first));
+        assert_eq!(
+            <TargetNotes<Test>>::iter_key_prefix(VotingModule::target_key(&elsewhere)).count(),
+            0
+        );
+    });
+}
+
+/// The feed the offchain worker imports in these tests.
+const FEED: &str = "http://localhost:8080/feed.json";
+
+#[test]
+fn feed_is_parsed_into_targets() {
+    let feed = br#"[
+        {"platform": "x.com", "id": "1", "timestamp": 1715000000},
+        {"platform": "reddit.com", "id": "abc"},
+        {"platform": "", "id": "2"},
+        {"platform": "x.com", "id": ""},
+        {"id": "3"},
+        {"platform": "x.com", "id": 4}
+    ]"#;
+    let targets = offchain::parse_feed::<BlakeTwo256>(feed).unwrap();
+    assert_eq!(
+        targets,
+        vec![
+            NoteTarget {
+                platform: b"x.com".to_vec().try_into().unwrap(),
+                content: BlakeTwo256::hash(b"1"),
+                timestamp: Some(1_715_000_000),
+            },
+            NoteTarget {
+                platform: b"reddit.com".to_vec().try_into().unwrap(),
+                content: BlakeTwo256::hash(b"abc"),
+                timestamp: None,
+            },
+        ]
+    );
+
+    assert_eq!(
+        offchain::parse_feed::<BlakeTwo256>(br#"{"platform": "x.com"}"#),
+        Err(ImportError::InvalidFeed)
+    );
+    assert_eq!(
+        offchain::parse_feed::<BlakeTwo256>(b"not json"),
+        Err(ImportError::InvalidFeed)
+    );
+}
+
+#[test]
+fn offchain_worker_submits_new_posts() {
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    let key = importer_key(&mut ext);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(VotingModule::import_feed(1), Err(ImportError::NoFeed));
+
+        // a post that already has a note is not imported again
+        let origin = RuntimeOrigin::signed(get_alice());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        assert_ok!(VotingModule::join_committee(origin.clone()));
+        let mut annotated = target();
+        annotated.content = BlakeTwo256::hash(b"annotated");
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(1)),
+            annotated,
+            100,
+            None,
+            None
+        ));
+
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            offchain::FEED_URL_KEY,
+            FEED.as_bytes(),
+        );
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: FEED.into(),
+            response: Some(
+                br#"[
+                    {"platform": "x.com", "id": "annotated"},
+                    {"platform": "x.com", "id": "1"},
+                    {"platform": "x.com", "id": "1", "timestamp": 1715000000},
+                    {"platform": "reddit.com", "id": "abc"}
+                ]"#
+                .to_vec(),
+            ),
+            sent: true,
+            ..Default::default()
+        });
+        assert_eq!(VotingModule::import_feed(1), Ok(2));
+
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        let candidates = ImportBatchOf::<Test>::truncate_from(vec![
+            NoteTarget {
+                platform: b"x.com".to_vec().try_into().unwrap(),
+                content: BlakeTwo256::hash(b"1"),
+                timestamp: None,
+            },
+            NoteTarget {
+                platform: b"reddit.com".to_vec().try_into().unwrap(),
+                content: BlakeTwo256::hash(b"abc"),
+                timestamp: None,
+            },
+        ]);
+        let RuntimeCall::VotingModule(call) = tx.function else {
+            panic!("not a call of the voting pallet");
+        };
+        let crate::Call::submit_candidates { payload, signature } = call.clone() else {
+            panic!("not an import");
+        };
+        assert_eq!(payload, import_payload(key, candidates.into_inner()));
+        assert!(SignedPayload::<Test>::verify::<ImporterId>(
+            &payload, signature
+        ));
+        <Importers<Test>>::insert(importer_account(key), ());
+        assert!(VotingModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+
+        // nothing is fetched before the next import is accepted
+        <NextImportAt<Test>>::put(2);
+        assert_eq!(VotingModule::import_feed(1), Ok(0));
+    });
+}
+
+#[test]
+fn offchain_worker_needs_an_importer_key() {
+    let (offchain, offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            offchain::FEED_URL_KEY,
+            FEED.as_bytes(),
+        );
+        offchain_state.write().expect_request(testing::PendingRequest {
+            method: "GET".into(),
+            uri: FEED.into(),
+            response: Some(br#"[{"platform": "x.com", "id": "1"}]"#.to_vec()),
+            sent: true,
+            ..Default::default()
+        });
+        assert_eq!(VotingModule::import_feed(1), Err(ImportError::NoKey));
+        assert!(pool_state.read().transactions.is_empty());
+    });
+}
+
+#[test]
+fn imports_need_an_importer_signature() {
+    let mut ext = new_test_ext();
+    let key = importer_key(&mut ext);
+
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        let payload = import_payload(key, vec![target()]);
+        let signature = SignedPayload::<Test>::sign::<ImporterId>(&payload).unwrap();
+        let call = crate::Call::submit_candidates {
+            payload: payload.clone(),
+            signature: signature.clone(),
+        };
+        assert_eq!(
+            VotingModule::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        assert_noop!(
+            VotingModule::set_importer(
+                RuntimeOrigin::signed(get_alice()),
+                importer_account(key),
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(VotingModule::set_importer(
+            RuntimeOrigin::root(),
+            importer_account(key),
+            true
+        ));
+        System::assert_last_event(
+            Event::<Test>::ImporterSet {
+                account: importer_account(key),
+                allowed: true,
+            }
+            .into(),
+        );
+        // any block author can include a signed import
+        assert!(VotingModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+        assert!(VotingModule::validate_unsigned(TransactionSource::InBlock, &call).is_ok());
+
+        // posts changed after signing
+        let mut forged = payload.clone();
+        forged.candidates = ImportBatchOf::<Test>::truncate_from(vec![target(), target_of(2)]);
+        assert_eq!(
+            VotingModule::validate_unsigned(
+                TransactionSource::InBlock,
+                &crate::Call::submit_candidates {
+                    payload: forged,
+                    signature: signature.clone(),
+                }
+            ),
+            InvalidTransaction::BadProof.into()
+        );
+
+        let mut empty = payload.clone();
+        empty.candidates = Default::default();
+        let signature_of_empty = SignedPayload::<Test>::sign::<ImporterId>(&empty).unwrap();
+        assert_eq!(
+            VotingModule::validate_unsigned(
+                TransactionSource::Local,
+                &crate::Call::submit_candidates {
+                    payload: empty,
+                    signature: signature_of_empty,
+                }
+            ),
+            InvalidTransaction::Call.into()
+        );
+
+        // the import cannot be replayed
+        assert_ok!(VotingModule::submit_candidates(
+            RuntimeOrigin::none(),
+            payload.clone(),
+            signature.clone()
+        ));
+        assert_eq!(
+            VotingModule::validate_unsigned(TransactionSource::InBlock, &call),
+            InvalidTransaction::Stale.into()
+        );
+        System::set_block_number(1 + ImportInterval::get());
+        assert_noop!(
+            VotingModule::submit_candidates(RuntimeOrigin::none(), payload, signature),
+            Error::<Test>::StaleImport
+        );
+
+        // nor signed by a former importer
+        let payload = import_payload(key, vec![target_of(2)]);
+        let signature = SignedPayload::<Test>::sign::<ImporterId>(&payload).unwrap();
+        assert_ok!(VotingModule::set_importer(
+            RuntimeOrigin::root(),
+            importer_account(key),
+            false
+        ));
+        assert_eq!(
+            VotingModule::validate_unsigned(
+                TransactionSource::InBlock,
+                &crate::Call::submit_candidates {
+                    payload: payload.clone(),
+                    signature: signature.clone(),
+                }
+            ),
+            InvalidTransaction::BadSigner.into()
+        );
+        assert_noop!(
+            VotingModule::submit_candidates(RuntimeOrigin::none(), payload, signature),
+            Error::<Test>::NotImporter
+        );
+    });
+}
+
+#[test]
+fn candidates_wait_for_review() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let origin = RuntimeOrigin::signed(get_alice());
+        assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+        assert_ok!(VotingModule::join_committee(origin.clone()));
+
+        let posts: Vec<NoteTargetOf<Test>> = (0..4u8)
+            .map(|i| {
+                let mut post = target();
+                post.content = H256::repeat_byte(i);
+                post
+            })
+            .collect();
+        let key = sr25519::Public::from_raw([1; 32]);
+        <Importers<Test>>::insert(importer_account(key), ());
+        assert_noop!(
+            VotingModule::submit_candidates(
+                RuntimeOrigin::signed(get_alice()),
+                import_payload(key, posts.clone()),
+                unchecked_signature()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(import(posts.clone()));
+        assert_eq!(<Candidates<Test>>::count(), 4);
+        let key = VotingModule::target_key(&posts[0]);
+        System::assert_has_event(
+            Event::<Test>::CandidateImported {
+                key,
+                target: posts[0].clone(),
+            }
+            .into(),
+        );
+        assert_eq!(<NextImportAt<Test>>::get(), 1 + ImportInterval::get());
+        assert_noop!(import(posts.clone()), Error::<Test>::ImportTooEarly);
+
+        // known posts are skipped and the queue stops at MaxCandidates
+        System::set_block_number(1 + ImportInterval::get());
+        let more: Vec<NoteTargetOf<Test>> = [2u8, 4, 5, 6]
+            .into_iter()
+            .map(|i| {
+                let mut post = target();
+                post.content = H256::repeat_byte(i);
+                post
+            })
+            .collect();
+        assert_ok!(import(more.clone()));
+        assert_eq!(<Candidates<Test>>::count(), MaxCandidates::get());
+        assert_eq!(VotingModule::candidates().len(), 6);
+        assert!(!<Candidates<Test>>::contains_key(VotingModule::target_key(
+            &more[3]
+        )));
+
+        // proposing a note about a candidate takes it off the queue
+        assert_ok!(VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            posts[0].clone(),
+            100,
+            None,
+            None
+        ));
+        assert!(!<Candidates<Test>>::contains_key(key));
+
+        let dismissed = VotingModule::target_key(&posts[1]);
+        assert_noop!(
+            VotingModule::dismiss_candidate(RuntimeOrigin::signed(get_bob()), dismissed),
+            Error::<Test>::NotMember
+        );
+        assert_ok!(VotingModule::dismiss_candidate(origin.clone(), dismissed));
+        System::assert_last_event(
+            Event::<Test>::CandidateDismissed {
+                key: dismissed,
+                account: get_alice(),
+            }
+            .into(),
+        );
+        assert_noop!(
+            VotingModule::dismiss_candidate(origin, dismissed),
+            Error::<Test>::CandidateMissing
+        );
+        assert_eq!(<Candidates<Test>>::count(), 4);
+    });
+}
+
+#[test]
+fn unreviewed_candidates_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(import(vec![target()]));
+        let key = VotingModule::target_key(&target());
+        let origin = RuntimeOrigin::signed(get_bob());
+        assert_noop!(
+            VotingModule::expire_candidate(origin.clone(), key),
+            Error::<Test>::CandidateNotExpired
+        );
+
+        System::set_block_number(1 + CandidateLifetime::get());
+        assert_ok!(VotingModule::expire_candidate(origin.clone(), key));
+        System::assert_last_event(Event::<Test>::CandidateExpired { key }.into());
+        assert_eq!(<Candidates<Test>>::count(), 0);
+        assert_noop!(
+            VotingModule::expire_candidate(origin, key),
+            Error::<Test>::CandidateMissing
+        );
+    });
+}
+
+#[test]
+fn delegated_votes_follow_the_delegate() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            join(who);
+        }
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(alice.clone()),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        // alice -> dave -> bob
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(dave.clone()),
+            bob.clone(),
+            None
+        ));
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(alice.clone()),
+            dave.clone(),
+            None
+        ));
+        // dave can only afford 2 votes
+        <Members<Test>>::mutate(&dave, |member| member.voting_tokens = 5);
+        let (commitment, _) = generate(&bob, proposal_hash, HELPFUL);
+        let bob_origin = RuntimeOrigin::signed(bob.clone());
+        let _ = VotingModule::commit_vote(bob_origin.clone(), proposal_hash, commitment, 3);
+
+        run_to_block(101);
+        assert_ok!(VotingModule::reveal_vote(
+            bob_origin,
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+        System::assert_has_event(
+            Event::DelegatedVote {
+                delegator: dave.clone(),
+                delegate: bob.clone(),
+                proposal_hash,
+                number: 2,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::DelegatedVote {
+                delegator: alice.clone(),
+                delegate: dave.clone(),
+                proposal_hash,
+                number: 2,
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Votes<Test>>::get(proposal_hash, &dave),
+            Some(VoteRecord {
+                number: 2,
+                option: HELPFUL
+            })
+        );
+        assert_eq!(<Members<Test>>::get(&dave).voting_tokens, 1);
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, 96);
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal.tally.into_inner(), vec![7, 0, 0]);
+        assert_eq!(proposal.voters, 3);
+        assert_eq!(proposal.revealed, 3);
+    });
+}
+
+#[test]
+fn committing_overrides_the_delegation() {
+    new_test_ext().execute_with(|| {
+        let (bob, dave) = (get_bob(), get_dave());
+        join(&bob);
+        join(&dave);
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(dave.clone()),
+            bob.clone(),
+            None
+        ));
+        let proposal_hash = vote_on_proposal(
+            &get_alice(),
+            &[(bob.clone(), 3, HELPFUL), (dave.clone(), 2, NOT_HELPFUL)],
+        );
+        assert_eq!(
+            <Votes<Test>>::get(proposal_hash, &dave),
+            Some(VoteRecord {
+                number: 2,
+                option: NOT_HELPFUL
+            })
+        );
+        let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal.tally.into_inner(), vec![3, 0, 2]);
+    });
+}
+
+#[test]
+fn platform_delegation_takes_precedence() {
+    new_test_ext().execute_with(|| {
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            join(who);
+        }
+        let dave_origin = RuntimeOrigin::signed(dave.clone());
+        assert_ok!(VotingModule::delegate(
+            dave_origin.clone(),
+            bob.clone(),
+            None
+        ));
+        assert_ok!(VotingModule::delegate(
+            dave_origin.clone(),
+            alice.clone(),
+            Some(target().platform)
+        ));
+        assert_eq!(
+            VotingModule::delegate_of(&dave, &Some(target().platform)),
+            Some(alice.clone())
+        );
+        assert_eq!(
+            VotingModule::delegate_of(&dave, &Some(b"y.com".to_vec().try_into().unwrap())),
+            Some(bob.clone())
+        );
+
+        let proposal_hash = vote_on_proposal(&alice, &[(bob.clone(), 3, HELPFUL)]);
+        assert_eq!(<Votes<Test>>::get(proposal_hash, &dave), None);
+
+        // without the platform delegation the general one applies
+        assert_ok!(VotingModule::undelegate(
+            dave_origin,
+            Some(target().platform)
+        ));
+        assert_eq!(<Delegators<Test>>::get(&alice).len(), 0);
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(alice.clone()),
+            Box::new(note(2)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[1];
+        let (commitment, _) = generate(&bob, proposal_hash, HELPFUL);
+        let bob_origin = RuntimeOrigin::signed(bob.clone());
+        let _ = VotingModule::commit_vote(bob_origin.clone(), proposal_hash, commitment, 1);
+        run_to_block(202);
+        assert_ok!(VotingModule::reveal_vote(
+            bob_origin,
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+        assert_eq!(
+            <Votes<Test>>::get(proposal_hash, &dave),
+            Some(VoteRecord {
+                number: 1,
+                option: HELPFUL
+            })
+        );
+    });
+}
+
+#[test]
+fn delegation_chains_are_checked() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, charlie, dave) = (get_alice(), get_bob(), get_charlie(), get_dave());
+        Balances::make_free_balance_be(&charlie, EntryFee::get() * 10);
+        for who in [&alice, &bob, &charlie, &dave] {
+            join(who);
+        }
+        let delegate = |from: &AccountId, to: &AccountId| {
+            VotingModule::delegate(RuntimeOrigin::signed(from.clone()), to.clone(), None)
+        };
+
+        assert_noop!(delegate(&alice, &alice), Error::<Test>::SelfDelegation);
+        assert_noop!(
+            VotingModule::delegate(
+                RuntimeOrigin::signed(alice.clone()),
+                bob.clone(),
+                Some(Default::default())
+            ),
+            Error::<Test>::InvalidTarget
+        );
+        assert_ok!(delegate(&bob, &dave));
+        assert_noop!(delegate(&dave, &bob), Error::<Test>::DelegationCycle);
+        assert_ok!(delegate(&alice, &bob));
+        // charlie -> alice -> bob -> dave is longer than MaxDelegationDepth
+        assert_noop!(delegate(&charlie, &alice), Error::<Test>::DelegationTooDeep);
+        assert_noop!(
+            VotingModule::undelegate(RuntimeOrigin::signed(charlie.clone()), None),
+            Error::<Test>::NotDelegated
+        );
+
+        // a new delegation on the same topic replaces the previous one
+        assert_ok!(delegate(&alice, &dave));
+        assert_eq!(
+            <Delegations<Test>>::get(&alice).into_inner(),
+            vec![(None, dave.clone())]
+        );
+        assert!(<Delegators<Test>>::get(&bob).is_empty());
+        assert_ok!(delegate(&charlie, &dave));
+        assert_eq!(<Delegators<Test>>::get(&dave).len(), 3);
+        System::assert_last_event(
+            Event::Delegated {
+                delegator: charlie,
+                delegate: dave,
+                topic: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn delegated_votes_keep_the_delegator_in_the_committee() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            join(who);
+        }
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(alice.clone()),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(dave.clone()),
+            bob.clone(),
+            None
+        ));
+        let (commitment, _) = generate(&bob, proposal_hash, HELPFUL);
+        let bob_origin = RuntimeOrigin::signed(bob.clone());
+        let _ = VotingModule::commit_vote(bob_origin.clone(), proposal_hash, commitment, 3);
+        run_to_block(101);
+        assert_ok!(VotingModule::reveal_vote(
+            bob_origin,
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+
+        // dave has no commit but a vote was given on its behalf
+        assert!(<Votes<Test>>::contains_key(proposal_hash, &dave));
+        assert_noop!(
+            VotingModule::leave_committee(RuntimeOrigin::signed(dave.clone())),
+            Error::<Test>::InMotion
+        );
+
+        run_to_block(151);
+        assert_ok!(VotingModule::leave_committee(RuntimeOrigin::signed(
+            dave.clone()
+        )));
+        assert!(!VotingModule::is_member(&dave));
+        assert_eq!(Balances::reserved_balance(&dave), 0);
+    });
+}
+
+#[test]
+fn former_members_are_not_recreated() {
+    new_test_ext().execute_with(|| {
+        Credits::set(CreditPolicy::Epochs {
+            length: 10,
+            grant: 10,
+            carry_over: None,
+        });
+        System::set_block_number(20);
+        let (bob, pot) = (get_bob(), VotingModule::account_id());
+        VotingModule::deposit_votes(&bob, 10);
+        VotingModule::set_reserved_balance(&bob, 10);
+        assert_ok!(VotingModule::slash_voting_side(
+            vec![(bob.clone(), 1)],
+            &pot
+        ));
+        assert_ok!(VotingModule::reward_voting_side(
+            vec![(bob.clone(), 1)],
+            &pot,
+            0
+        ));
+        assert!(!VotingModule::replenish_member(&bob));
+        assert!(!VotingModule::is_member(&bob));
+    });
+}
+
+#[test]
+fn leaving_clears_delegations() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            join(who);
+        }
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(alice.clone()),
+            bob.clone(),
+            None
+        ));
+        assert_ok!(VotingModule::delegate(
+            RuntimeOrigin::signed(bob.clone()),
+            dave.clone(),
+            None
+        ));
+
+        assert_ok!(VotingModule::leave_committee(RuntimeOrigin::signed(
+            bob.clone()
+        )));
+        assert!(!<Delegations<Test>>::contains_key(&alice));
+        assert!(!<Delegations<Test>>::contains_key(&bob));
+        assert!(!<Delegators<Test>>::contains_key(&bob));
+        assert!(!<Delegators<Test>>::contains_key(&dave));
+        System::assert_has_event(
+            Event::Undelegated {
+                delegator: alice,
+                topic: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn tie_without_member_proposer_is_unclaimed() {
+    new_test_ext().execute_with(|| {
+        let proposal_hash = vote_on_proposal(
+            &get_dave(),
+            &[(get_alice(), 1, HELPFUL), (get_bob(), 1, NOT_HELPFUL)],
+        );
+        // dave proposed and left the committee
+        assert_ok!(VotingModule::leave_committee(RuntimeOrigin::signed(
+            get_dave()
+        )));
+        run_to_block(151);
+
+        let slash = SideSlash::get().mul_floor(EntryFee::get());
+        assert_eq!(
+            <ProposalResults<Test>>::get(proposal_hash).unwrap().outcome,
+            Outcome::Tie
+        );
+        assert!(!VotingModule::is_member(&get_dave()));
+        assert_eq!(
+            <Pot<Test>>::get(),
+            PotLedger {
+                inflow: slash * 2,
+                unclaimed: slash * 2,
+                ..Default::default()
+            }
+        );
+    });
+}
+
+#[test]
+fn approved_proposal_returns_the_bond() {
+    new_test_ext().execute_with(|| {
+        let dave = get_dave();
+        let proposal_hash = vote_on_proposal(&dave, &[(get_alice(), 2, HELPFUL)]);
+        System::assert_has_event(
+            Event::BondReserved {
+                proposal_hash,
+                proposer: dave.clone(),
+                amount: ProposalBond::get(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            Balances::reserved_balance(&dave),
+            EntryFee::get() + ProposalBond::get()
+        );
+        run_to_block(151);
+
+        assert!(!<ProposalBonds<Test>>::contains_key(proposal_hash));
+        assert_eq!(Balances::reserved_balance(&dave), EntryFee::get());
+        System::assert_has_event(
+            Event::BondReturned {
+                proposal_hash,
+                proposer: dave,
+                amount: ProposalBond::get(),
+            }
+            .into(),
+        );
+        assert_eq!(<Pot<Test>>::get(), PotLedger::default());
+    });
+}
+
+#[test]
+fn disapproved_proposal_slashes_the_bond() {
+    new_test_ext().execute_with(|| {
+        let dave = get_dave();
+        let proposal_hash = vote_on_proposal(&dave, &[(get_alice(), 2, NOT_HELPFUL)]);
+        let free = Balances::free_balance(&dave);
+        run_to_block(151);
+
+        let slash = BondSlash::get().mul_floor(ProposalBond::get());
+        assert_eq!(Balances::reserved_balance(&dave), EntryFee::get());
+        assert_eq!(
+            Balances::free_balance(&dave),
+            free + ProposalBond::get() - slash
+        );
+        // the stake of the proposer as a member is left untouched
+        assert_eq!(
+            <Members<Test>>::get(&dave).reserved_balance,
+            EntryFee::get()
+        );
+        System::assert_has_event(
+            Event::BondSlashed {
+                proposal_hash,
+                proposer: dave.clone(),
+                amount: slash,
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::BondReturned {
+                proposal_hash,
+                proposer: dave,
+                amount: ProposalBond::get() - slash,
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Pot<Test>>::get(),
+            PotLedger {
+                inflow: slash,
+                unclaimed: slash,
+                ..Default::default()
+            }
+        );
+    });
+}
+
+#[test]
+fn tie_rewards_nobody() {
+    new_test_ext().execute_with(|| {
+        let dave = get_dave();
+        let proposal_hash = vote_on_proposal(
+            &dave,
+            &[(get_alice(), 1, HELPFUL), (get_bob(), 1, NOT_HELPFUL)],
+        );
+        assert_eq!(
+            VotingModule::estimate_payout(&dave, proposal_hash),
+            Some(PayoutEstimate::default())
+        );
+        run_to_block(151);
+
+        let slash = SideSlash::get().mul_floor(EntryFee::get());
+        assert_eq!(
+            <ProposalResults<Test>>::get(proposal_hash).unwrap().outcome,
+            Outcome::Tie
+        );
+        // the proposer gets its bond back but none of the slashes
+        assert_eq!(Balances::reserved_balance(&dave), EntryFee::get());
+        assert_eq!(VotingModule::reputation(&dave).rewarded, 0);
+        System::assert_has_event(
+            Event::BondReturned {
+                proposal_hash,
+                proposer: dave,
+                amount: ProposalBond::get(),
+            }
+            .into(),
+        );
+        assert_eq!(
+            <Pot<Test>>::get(),
+            PotLedger {
+                inflow: slash * 2,
+                unclaimed: slash * 2,
+                ..Default::default()
+            }
+        );
+    });
+}
+
+#[test]
+fn proposing_needs_the_bond() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        join(&alice);
+        Balances::make_free_balance_be(&alice, ProposalBond::get() - 1);
+        assert_noop!(
+            VotingModule::create_proposal(
+                RuntimeOrigin::signed(alice),
+                Box::new(note(1)),
+                target(),
+                100,
+                None,
+                None,
+            ),
+            Error::<Test>::NotEnoughFunds
+        );
+    });
+}
+
+#[test]
+fn pot_can_be_burned() {
+    new_test_ext().execute_with(|| {
+        vote_on_proposal(
+            &get_alice(),
+            &[
+                (get_alice(), 3, HELPFUL),
+                (get_bob(), 4, HELPFUL),
+                (get_dave(), 2, NOT_HELPFUL),
+            ],
+        );
+        run_to_block(151);
+        let pot = VotingModule::account_id();
+        let dust = <Pot<Test>>::get().dust;
+        let issuance = Balances::total_issuance();
+
+        assert_noop!(
+            VotingModule::spend_pot(RuntimeOrigin::signed(get_alice()), dust, PotSpend::Burn),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            VotingModule::spend_pot(RuntimeOrigin::root(), dust + 1, PotSpend::Burn),
+            Error::<Test>::InsufficientPot
+        );
+        assert_ok!(VotingModule::spend_pot(
+            RuntimeOrigin::root(),
+            dust,
+            PotSpend::Burn
+        ));
+
+        System::assert_last_event(Event::<Test>::PotBurned { amount: dust }.into());
+        let ledger = <Pot<Test>>::get();
+        assert_eq!((ledger.burned, ledger.dust), (dust, 0));
+        assert_eq!(Balances::reserved_balance(&pot), 0);
+        assert_eq!(Balances::total_issuance(), issuance - dust);
+    });
+}
+
+#[test]
+fn pot_can_be_redistributed() {
+    new_test_ext().execute_with(|| {
+        let alice = get_alice();
+        let bob = get_bob();
+        let proposal_hash = vote_on_proposal(&alice, &[(alice.clone(), 1, HELPFUL)]);
+        run_to_block(151);
+        // bob revealing late fills the pot
+        let origin = RuntimeOrigin::signed(bob.clone());
+        let _ = Identity::set_identity(origin.clone(), Box::new(data()));
+        let _ = VotingModule::join_committee(origin.clone());
+        <Commits<Test>>::insert(
+            &bob,
+            proposal_hash,
+            types::Commit {
+                commitment: generate(&bob, proposal_hash, HELPFUL).0,
+                number: 1,
+            },
+        );
+        assert_ok!(VotingModule::reveal_vote(
+            origin,
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+        let unclaimed = <Pot<Test>>::get().unclaimed;
+        let reserved = |who: &AccountId| <Members<Test>>::get(who).reserved_balance;
+        let before = (reserved(&alice), reserved(&bob));
+
+        let beneficiaries =
+            |accounts: Vec<AccountId>| PotSpend::Redistribute(accounts.try_into().unwrap());
+        let too_many = vec![alice.clone(), bob.clone(), get_charlie()];
+        assert_eq!(too_many.len() as u32, MaxBeneficiaries::get() + 1);
+        assert!(BoundedVec::<AccountId, MaxBeneficiaries>::try_from(too_many).is_err());
+        assert_noop!(
+            VotingModule::spend_pot(RuntimeOrigin::root(), unclaimed, beneficiaries(vec![])),
+            Error::<Test>::NoBeneficiaries
+        );
+        assert_noop!(
+            VotingModule::spend_pot(
+                RuntimeOrigin::root(),
+                unclaimed,
+                beneficiaries(vec![alice.clone(), get_dave()])
+            ),
+            Error::<Test>::NotMember
+        );
+        assert_ok!(VotingModule::spend_pot(
+            RuntimeOrigin::root(),
+            unclaimed,
+            beneficiaries(vec![alice.clone(), bob.clone()])
+        ));
+
+        let share = unclaimed / 2;
+        assert_eq!(reserved(&alice), before.0 + share);
+        assert_eq!(reserved(&bob), before.1 + share);
+        let ledger = <Pot<Test>>::get();
+        assert_eq!(ledger.distributed, share * 2);
+        assert_eq!(ledger.unclaimed, 0);
+        assert_eq!(ledger.dust, unclaimed - share * 2);
+        System::assert_last_event(
+            Event::<Test>::PotRedistributed {
+                amount: share * 2,
+                beneficiaries: 2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v7_records_the_pot() {
+    new_test_ext().execute_with(|| {
+        let pot = VotingModule::account_id();
+        let _ = Balances::deposit_creating(&pot, 500);
+        assert_ok!(Balances::reserve(&pot, 400));
+        StorageVersion::new(6).put::<VotingModule>();
+
+        v7::MigrateToV7::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            <Pot<Test>>::get(),
+            PotLedger {
+                inflow: 400,
+                unclaimed: 400,
+                ..Default::default()
+            }
+        );
+        assert_eq!(StorageVersion::get::<VotingModule>(), 7);
+    });
+}
+
+#[test]
+fn genesis_adds_members_and_proposals() {
+    let alice = get_alice();
+    let bob = get_bob();
+    let genesis = crate::GenesisConfig::<Test> {
+        identities: vec![
+            (alice.clone(), b"alice".to_vec()),
+            (bob.clone(), b"bob".to_vec()),
+        ],
+        members: vec![
+            (alice.clone(), 50, EntryFee::get()),
+            (bob.clone(), MaxTokens::get(), EntryFee::get() * 2),
+        ],
+        proposals: vec![(
+            alice.clone(),
+            b"note".to_vec(),
+            (b"x.com".to_vec(), H256::repeat_byte(1)),
+            100,
+        )],
+        importers: vec![get_dave()],
+        ..Default::default()
+    };
+    new_test_ext_with(genesis).execute_with(|| {
+        assert_eq!(<Members<Test>>::count(), 2);
+        assert!(<Importers<Test>>::contains_key(get_dave()));
+        assert_eq!(
+            VotingModule::member(&alice),
+            Some(types::VoterBalance {
+                voting_tokens: 50,
+                reserved_balance: EntryFee::get(),
+            })
+        );
+        assert_eq!(
+            <Members<Test>>::get(&bob).reserved_balance,
+            EntryFee::get() * 2
+        );
+        assert!(Balances::reserved_balance(&bob) >= EntryFee::get() * 2);
+        assert!(Identity::identity(bob).is_some());
+
+        let proposals = <Proposals<Test>>::get();
+        assert_eq!(proposals.len(), 1);
+        let proposal = <ProposalData<Test>>::get(proposals[0]).unwrap();
+        assert_eq!(proposal.proposer, alice);
+        assert_eq!(
+            proposal.title,
+            Data::Raw(b"note".to_vec().try_into().unwrap())
+        );
+        assert_eq!(proposal.poll_end, 100);
+        assert_eq!(
+            VotingModule::notes_for_target(&target()),
+            vec![proposals[0]]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis member has no identity")]
+fn genesis_members_need_an_identity() {
+    new_test_ext_with(crate::GenesisConfig::<Test> {
+        members: vec![(get_alice(), MaxTokens::get(), EntryFee::get())],
+        ..Default::default()
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis member deposit is below BasicDeposit")]
+fn genesis_members_reserve_the_deposit() {
+    new_test_ext_with(crate::GenesisConfig::<Test> {
+        identities: vec![(get_alice(), b"alice".to_vec())],
+        members: vec![(get_alice(), MaxTokens::get(), EntryFee::get() - 1)],
+        ..Default::default()
+    });
+}
+
+#[test]
+fn genesis_judges_identities() {
+    let genesis = crate::GenesisConfig::<Test> {
+        identities: vec![(get_alice(), b"alice".to_vec())],
+        registrar: Some(get_bob()),
+        ..Default::default()
+    };
+    new_test_ext_with(genesis).execute_with(|| {
+        let registrars = Identity::registrars();
+        assert_eq!(registrars.len(), 1);
+        assert_eq!(registrars[0].as_ref().unwrap().account, get_bob());
+        let (registration, _) = Identity::identity(get_alice()).unwrap();
+        assert_eq!(
+            registration.judgements.into_inner(),
+            vec![(0, pallet_identity::Judgement::Reasonable)]
+        );
+    });
+}
+
+#[test]
+fn seeding_errors_are_propagated() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            VotingIdentityProvider::seed_judgement(&get_bob(), &get_alice()),
+            pallet_identity::Error::<Test>::NoIdentity
+        );
+        assert!(Identity::registrars().is_empty());
+    });
+}
+
+#[test]
+fn genesis_proposals_can_start_revealing() {
+    let alice = get_alice();
+    let genesis = crate::GenesisConfig::<Test> {
+        identities: vec![(alice.clone(), b"alice".to_vec())],
+        members: vec![(alice.clone(), MaxTokens::get(), EntryFee::get())],
+        proposals: vec![(
+            alice.clone(),
+            b"open".to_vec(),
+            (b"x.com".to_vec(), H256::repeat_byte(1)),
+            100,
+        )],
+        revealing: vec![(
+            alice.clone(),
+            b"revealing".to_vec(),
+            (b"x.com".to_vec(), H256::repeat_byte(2)),
+        )],
+        ..Default::default()
+    };
+    new_test_ext_with(genesis).execute_with(|| {
+        let proposals = <Proposals<Test>>::get();
+        assert_eq!(proposals.len(), 2);
+        let open = <ProposalData<Test>>::get(proposals[0]).unwrap();
+        assert_eq!(open.reveal_end, None);
+        let revealing = <ProposalData<Test>>::get(proposals[1]).unwrap();
+        assert_eq!(revealing.poll_end, 0);
+        assert_eq!(revealing.reveal_end, Some(RevealLength::get()));
+        assert_eq!(
+            <Deadlines<Test>>::get(MinLength::get()).into_inner(),
+            vec![(proposals[0], Phase::Commit)]
+        );
+        assert_eq!(
+            <Deadlines<Test>>::get(RevealLength::get()).into_inner(),
+            vec![(proposals[1], Phase::Reveal)]
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "genesis proposal is valid")]
+fn genesis_proposals_need_a_member() {
+    new_test_ext_with(crate::GenesisConfig::<Test> {
+        proposals: vec![(
+            get_alice(),
+            b"note".to_vec(),
+            (b"x.com".to_vec(), H256::repeat_byte(1)),
+            100,
+        )],
+        ..Default::default()
+    });
+}
+
+#[test]
+fn credits_regenerate_in_epochs() {
+    new_test_ext().execute_with(|| {
+        Credits::set(CreditPolicy::Epochs {
+            length: 200,
+            grant: 60,
+            carry_over: Some(20),
+        });
+        let (alice, bob) = (get_alice(), get_bob());
+        vote_on_proposal(&alice, &[(bob.clone(), 5, HELPFUL)]);
+        run_to_block(151);
+        // nothing is refunded when the proposal closes
+        assert_eq!(<Members<Test>>::get(&bob).voting_tokens, 75);
+
+        run_to_block(200);
+        System::assert_has_event(Event::EpochStarted { epoch: 1 }.into());
+        // room for a single member
+        let db: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+        let one =
+            <() as crate::WeightInfo>::replenish_credits().saturating_add(db.reads_writes(2, 2));
+        VotingModule::replenish_members(one);
+        assert!(<ReplenishCursor<Test>>::get().is_some());
+        VotingModule::on_idle(200, Weight::MAX);
+        assert_eq!(<ReplenishCursor<Test>>::get(), None);
+        assert_eq!(<ReplenishedEpoch<Test>>::get(), 1);
+
+        // unspent credits are capped before the grant is added
+        assert_eq!(<Members<Test>>::get(&bob).voting_tokens, 80);
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, 80);
+        assert_eq!(<CreditEpochs<Test>>::get(&bob), 1);
+        System::assert_has_event(
+            Event::CreditsReplenished {
+                account: bob.clone(),
+                epoch: 1,
+                credits: 80,
+            }
+            .into(),
+        );
+        assert_noop!(
+            VotingModule::replenish_credits(RuntimeOrigin::signed(alice), bob),
+            Error::<Test>::CreditsUpToDate
+        );
+    });
+}
+
+#[test]
+fn credits_are_replenished_on_commit() {
+    new_test_ext().execute_with(|| {
+        Credits::set(CreditPolicy::Epochs {
+            length: 10,
+            grant: MaxTokens::get(),
+            carry_over: None,
+        });
+        System::set_block_number(1);
+        let (alice, dave) = (get_alice(), get_dave());
+        join(&alice);
+        let origin = RuntimeOrigin::signed(alice.clone());
+        for i in 0..2 {
+            let _ = VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None,
+            );
+        }
+        let proposals = <Proposals<Test>>::get();
+        let commit = |proposal_hash: H256| {
+            let (commitment, _) = generate(&alice, proposal_hash, HELPFUL);
+            VotingModule::commit_vote(origin.clone(), proposal_hash, commitment, 10)
+        };
+        assert_ok!(commit(proposals[0]));
+        assert_noop!(commit(proposals[1]), Error::<Test>::NotEnoughVotingTokens);
+
+        run_to_block(10);
+        assert_ok!(commit(proposals[1]));
+        assert_eq!(<CreditEpochs<Test>>::get(&alice), 1);
+        assert_eq!(<Members<Test>>::get(&alice).voting_tokens, 0);
+
+        // members joining in an epoch start with their credits replenished
+        assert_noop!(
+            VotingModule::replenish_credits(RuntimeOrigin::signed(alice.clone()), dave.clone()),
+            Error::<Test>::NotMember
+        );
+        join(&dave);
+        assert_noop!(
+            VotingModule::replenish_credits(RuntimeOrigin::signed(alice), dave),
+            Error::<Test>::CreditsUpToDate
+        );
+    });
+}
+
+#[test]
+fn proposer_withdraws_before_commits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob) = (get_alice(), get_bob());
+        join(&alice);
+        join(&bob);
+        let origin = RuntimeOrigin::signed(alice.clone());
+        for i in 0..2 {
+            let _ = VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None,
+            );
+        }
+        let proposals = <Proposals<Test>>::get();
+        let (commitment, _) = generate(&bob, proposals[0], HELPFUL);
+        let _ = VotingModule::commit_vote(
+            RuntimeOrigin::signed(bob.clone()),
+            proposals[0],
+            commitment,
+            1,
+        );
+
+        assert_noop!(
+            VotingModule::withdraw_proposal(RuntimeOrigin::signed(bob), proposals[1]),
+            Error::<Test>::NotProposer
+        );
+        assert_noop!(
+            VotingModule::withdraw_proposal(origin.clone(), proposals[0]),
+            Error::<Test>::ProposalHasCommits
+        );
+        assert_ok!(VotingModule::withdraw_proposal(
+            origin.clone(),
+            proposals[1]
+        ));
+        System::assert_last_event(
+            Event::Withdrawn {
+                proposal_hash: proposals[1],
+                proposer: alice.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(<Proposals<Test>>::get().into_inner(), vec![proposals[0]]);
+        assert!(!<ProposalData<Test>>::contains_key(proposals[1]));
+        assert!(!<NoteTargets<Test>>::contains_key(proposals[1]));
+        assert_eq!(
+            VotingModule::notes_for_target(&target()),
+            vec![proposals[0]]
+        );
+        assert_eq!(
+            <Deadlines<Test>>::get(101).into_inner(),
+            vec![(proposals[0], Phase::Commit)]
+        );
+        assert_eq!(
+            Balances::reserved_balance(&alice),
+            EntryFee::get() + ProposalBond::get()
+        );
+
+        // the note can be proposed again
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None
+        ));
+    });
+}
+
+#[test]
+fn cancelled_proposal_refunds_everyone() {
+    new_test_ext().execute_with(|| {
+        Credits::set(CreditPolicy::Epochs {
+            length: 1_000,
+            grant: MaxTokens::get(),
+            carry_over: None,
+        });
+        System::set_block_number(1);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            join(who);
+        }
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(dave.clone()),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        for (who, number) in [(&alice, 3), (&bob, 4)] {
+            let (commitment, _) = generate(who, proposal_hash, HELPFUL);
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = VotingModule::commit_vote(origin, proposal_hash, commitment, number);
+        }
+        run_to_block(101);
+        assert_ok!(VotingModule::reveal_vote(
+            RuntimeOrigin::signed(alice.clone()),
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+
+        assert_noop!(
+            VotingModule::cancel_proposal(RuntimeOrigin::signed(dave.clone()), proposal_hash),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(VotingModule::cancel_proposal(
+            RuntimeOrigin::root(),
+            proposal_hash
+        ));
+        System::assert_last_event(
+            Event::Cancelled {
+                proposal_hash,
+                refunded: 2,
+            }
+            .into(),
+        );
+        // credits come back even though they are replenished in epochs
+        for who in [&alice, &bob] {
+            assert_eq!(<Members<Test>>::get(who).voting_tokens, MaxTokens::get());
+        }
+        assert_eq!(Balances::reserved_balance(&dave), EntryFee::get());
+        assert!(VotingModule::pending_commits(&bob).is_empty());
+        assert_eq!(<Votes<Test>>::get(proposal_hash, &alice), None);
+        assert!(<Deadlines<Test>>::get(151).is_empty());
+        assert!(<Proposals<Test>>::get().is_empty());
+
+        run_to_block(151);
+        assert!(!<ProposalResults<Test>>::contains_key(proposal_hash));
+        assert_noop!(
+            VotingModule::cancel_proposal(RuntimeOrigin::root(), proposal_hash),
+            Error::<Test>::ProposalMissing
+        );
+    });
+}
+
+#[test]
+fn cancelled_proposal_refunds_in_batches() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, charlie, dave) = (get_alice(), get_bob(), get_charlie(), get_dave());
+        Balances::make_free_balance_be(&charlie, EntryFee::get() * 10);
+        for who in [&alice, &bob, &charlie, &dave] {
+            join(who);
+        }
+        let proposer = RuntimeOrigin::signed(dave);
+        assert_ok!(VotingModule::create_proposal(
+            proposer.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        ));
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let voters = [&alice, &bob, &charlie];
+        assert!(voters.len() as u32 > ReapLimit::get());
+        for who in voters {
+            let (commitment, _) = generate(who, proposal_hash, HELPFUL);
+            let origin = RuntimeOrigin::signed(who.clone());
+            assert_ok!(VotingModule::commit_vote(
+                origin,
+                proposal_hash,
+                commitment,
+                3
+            ));
+        }
+
+        assert_ok!(VotingModule::cancel_proposal(
+            RuntimeOrigin::root(),
+            proposal_hash
+        ));
+        System::assert_last_event(
+            Event::Cancelled {
+                proposal_hash,
+                refunded: ReapLimit::get(),
+            }
+            .into(),
+        );
+        assert!(<CancelledProposals<Test>>::contains_key(proposal_hash));
+        assert!(<Proposals<Test>>::get().is_empty());
+        // the commits left are refunded, not reaped
+        assert_noop!(
+            VotingModule::reap_unrevealed(
+                RuntimeOrigin::signed(alice.clone()),
+                proposal_hash,
+                ReapLimit::get()
+            ),
+            Error::<Test>::RefundPending
+        );
+        assert_noop!(
+            VotingModule::create_proposal(
+                proposer.clone(),
+                Box::new(note(1)),
+                target(),
+                100,
+                None,
+                None,
+            ),
+            Error::<Test>::DuplicateProposal
+        );
+        assert_noop!(
+            VotingModule::refund_cancelled(
+                RuntimeOrigin::signed(alice.clone()),
+                proposal_hash,
+                ReapLimit::get() + 1
+            ),
+            Error::<Test>::InvalidArgument
+        );
+
+        assert_ok!(VotingModule::refund_cancelled(
+            RuntimeOrigin::signed(alice.clone()),
+            proposal_hash,
+            ReapLimit::get()
+        ));
+        System::assert_last_event(
+            Event::Refunded {
+                proposal_hash,
+                refunded: 1,
+            }
+            .into(),
+        );
+        assert!(!<CancelledProposals<Test>>::contains_key(proposal_hash));
+        for who in voters {
+            assert_eq!(<Members<Test>>::get(who).voting_tokens, MaxTokens::get());
+            assert!(VotingModule::pending_commits(who).is_empty());
+        }
+        assert_noop!(
+            VotingModule::refund_cancelled(
+                RuntimeOrigin::signed(alice),
+                proposal_hash,
+                ReapLimit::get()
+            ),
+            Error::<Test>::NotCancelled
+        );
+        assert_ok!(VotingModule::create_proposal(
+            proposer,
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        ));
+    });
+}
+
+#[test]
+fn admin_removes_a_member_and_its_votes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob, dave) = (get_alice(), get_bob(), get_dave());
+        for who in [&alice, &bob, &dave] {
+            join(who);
+        }
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(alice.clone()),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        for (who, number) in [(&bob, 3), (&dave, 2)] {
+            let (commitment, _) = generate(who, proposal_hash, HELPFUL);
+            let origin = RuntimeOrigin::signed(who.clone());
+            let _ = VotingModule::commit_vote(origin, proposal_hash, commitment, number);
+        }
+        run_to_block(101);
+        assert_ok!(VotingModule::reveal_vote(
+            RuntimeOrigin::signed(bob.clone()),
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+
+        assert_noop!(
+            VotingModule::force_remove_member(RuntimeOrigin::signed(alice.clone()), bob.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            VotingModule::force_remove_member(RuntimeOrigin::root(), get_charlie()),
+            Error::<Test>::NotMember
+        );
+
+        // the revealed votes are taken out of the tally
+        assert_ok!(VotingModule::force_remove_member(
+            RuntimeOrigin::root(),
+            bob.clone()
+        ));
+        System::assert_last_event(
+            Event::MemberRemoved {
+                account: bob.clone(),
+                cashout: EntryFee::get(),
+            }
+            .into(),
+        );
+        let proposal_data = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal_data.tally[HELPFUL as usize], 0);
+        assert_eq!(proposal_data.revealed, 0);
+        assert_eq!(proposal_data.voters, 1);
+        assert_eq!(<Votes<Test>>::get(proposal_hash, &bob), None);
+        assert_eq!(Balances::reserved_balance(&bob), 0);
+
+        // and the pending commits dropped
+        assert_ok!(VotingModule::force_remove_member(
+            RuntimeOrigin::root(),
+            dave.clone()
+        ));
+        assert!(!<Commits<Test>>::contains_key(&dave, proposal_hash));
+        assert!(!<Committers<Test>>::contains_key(proposal_hash, &dave));
+        let proposal_data = <ProposalData<Test>>::get(proposal_hash).unwrap();
+        assert_eq!(proposal_data.voters, 0);
+        assert_eq!(proposal_data.revealed, 0);
+
+        // closing the proposal does not bring them back
+        run_to_block(151);
+        assert!(<ProposalResults<Test>>::contains_key(proposal_hash));
+        assert!(!VotingModule::is_member(&bob));
+        assert!(!VotingModule::is_member(&dave));
+        assert_eq!(Balances::reserved_balance(&dave), 0);
+    });
+}
+
+#[test]
+fn admin_force_closes_phases() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (alice, bob) = (get_alice(), get_bob());
+        join(&alice);
+        join(&bob);
+        let _ = VotingModule::create_proposal(
+            RuntimeOrigin::signed(alice.clone()),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        );
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        let (commitment, _) = generate(&bob, proposal_hash, HELPFUL);
+        let _ = VotingModule::commit_vote(
+            RuntimeOrigin::signed(bob.clone()),
+            proposal_hash,
+            commitment,
+            2,
+        );
+
+        run_to_block(10);
+        assert_noop!(
+            VotingModule::force_close_vote(RuntimeOrigin::signed(alice), proposal_hash),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            VotingModule::force_close_reveal(RuntimeOrigin::root(), proposal_hash),
+            Error::<Test>::RevealNotStarted
+        );
+        assert_ok!(VotingModule::force_close_vote(
+            RuntimeOrigin::root(),
+            proposal_hash
+        ));
+        System::assert_last_event(
+            Event::ForceClosed {
+                proposal_hash,
+                phase: Phase::Commit,
+            }
+            .into(),
+        );
+        let reveal_end = 10 + RevealLength::get();
+        assert_eq!(
+            <ProposalData<Test>>::get(proposal_hash).unwrap().reveal_end,
+            Some(reveal_end)
+        );
+        assert!(<Deadlines<Test>>::get(101).is_empty());
+        assert_noop!(
+            VotingModule::force_close_vote(RuntimeOrigin::root(), proposal_hash),
+            Error::<Test>::VoteAlreadyEnded
+        );
+
+        assert_ok!(VotingModule::reveal_vote(
+            RuntimeOrigin::signed(bob.clone()),
+            proposal_hash,
+            HELPFUL,
+            SALT
+        ));
+        run_to_block(20);
+        assert_ok!(VotingModule::force_close_reveal(
+            RuntimeOrigin::root(),
+            proposal_hash
+        ));
+        System::assert_last_event(
+            Event::ForceClosed {
+                proposal_hash,
+                phase: Phase::Reveal,
+            }
+            .into(),
+        );
+        assert_eq!(
+            <ProposalResults<Test>>::get(proposal_hash).unwrap().outcome,
+            Outcome::Approved
+        );
+        assert!(<Deadlines<Test>>::get(reveal_end).is_empty());
+        assert_noop!(
+            VotingModule::force_close_reveal(RuntimeOrigin::root(), proposal_hash),
+            Error::<Test>::ProposalMissing
+        );
+    });
+}
+
+#[test]
+fn admin_sets_member_balance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let bob = get_bob();
+        join(&bob);
+        let balance = VoterBalance {
+            voting_tokens: 10,
+            reserved_balance: EntryFee::get() / 2,
+        };
+
+        assert_noop!(
+            VotingModule::force_set_member_balance(
+                RuntimeOrigin::signed(bob.clone()),
+                bob.clone(),
+                balance.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            VotingModule::force_set_member_balance(
+                RuntimeOrigin::root(),
+                get_alice(),
+                balance.clone()
+            ),
+            Error::<Test>::NotMember
+        );
+        assert_noop!(
+            VotingModule::force_set_member_balance(
+                RuntimeOrigin::root(),
+                bob.clone(),
+                VoterBalance {
+                    voting_tokens: MaxTokens::get() + 1,
+                    reserved_balance: 0,
+                }
+            ),
+            Error::<Test>::InvalidArgument
+        );
+        assert_ok!(VotingModule::force_set_member_balance(
+            RuntimeOrigin::root(),
+            bob.clone(),
+            balance.clone()
+        ));
+        System::assert_last_event(
+            Event::MemberBalanceSet {
+                account: bob.clone(),
+                balance: balance.clone(),
+            }
+            .into(),
+        );
+        assert_eq!(<Members<Test>>::get(&bob), balance);
+        // the difference is given back
+        assert_eq!(Balances::reserved_balance(&bob), EntryFee::get() / 2);
+
+        // and reserved again, as far as the member can afford it
+        let free = Balances::free_balance(&bob);
+        assert_noop!(
+            VotingModule::force_set_member_balance(
+                RuntimeOrigin::root(),
+                bob.clone(),
+                VoterBalance {
+                    voting_tokens: 10,
+                    reserved_balance: EntryFee::get() / 2 + free + 1,
+                }
+            ),
+            Error::<Test>::NotEnoughFunds
+        );
+        let balance = VoterBalance {
+            voting_tokens: 10,
+            reserved_balance: EntryFee::get() * 2,
+        };
+        assert_ok!(VotingModule::force_set_member_balance(
+            RuntimeOrigin::root(),
+            bob.clone(),
+            balance.clone()
+        ));
+        assert_eq!(Balances::reserved_balance(&bob), EntryFee::get() * 2);
+
+        // leaving gives back exactly the recorded funds
+        assert_ok!(VotingModule::leave_committee(RuntimeOrigin::signed(
+            bob.clone()
+        )));
+        assert_eq!(Balances::reserved_balance(&bob), 0);
+    });
+}
+
+#[test]
+fn forced_balance_cannot_release_missing_funds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let bob = get_bob();
+        join(&bob);
+        assert_ok!(VotingModule::create_proposal(
+            RuntimeOrigin::signed(bob.clone()),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None,
+        ));
+        // most of the deposit was slashed, the bond is still reserved
+        let _ = Balances::slash_reserved(&bob, EntryFee::get() * 3 / 4);
+        let reserved = Balances::reserved_balance(&bob);
+
+        assert_noop!(
+            VotingModule::force_set_member_balance(
+                RuntimeOrigin::root(),
+                bob.clone(),
+                VoterBalance {
+                    voting_tokens: 10,
+                    reserved_balance: 0,
+                }
+            ),
+            Error::<Test>::ReserveShortfall
+        );
+        assert_eq!(Balances::reserved_balance(&bob), reserved);
+
+        // what is left of the deposit can be given back
+        assert_ok!(VotingModule::force_set_member_balance(
+            RuntimeOrigin::root(),
+            bob.clone(),
+            VoterBalance {
+                voting_tokens: 10,
+                reserved_balance: EntryFee::get() * 3 / 4,
+            }
+        ));
+        assert_eq!(
+            Balances::reserved_balance(&bob),
+            reserved - EntryFee::get() / 4
+        );
+        assert_eq!(VotingModule::bonds_of(&bob), ProposalBond::get());
+    });
+}
+#[test]
+fn parameters_are_bounded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            VotingModule::set_parameter(
+                RuntimeOrigin::signed(get_alice()),
+                VotingParameter::EntryFee(Some(1))
+            ),
+            DispatchError::BadOrigin
+        );
+        for parameter in [
+            VotingParameter::RevealLength(Some(0)),
+            VotingParameter::MinLength(Some(0)),
+            VotingParameter::MaxVotingTokens(Some(0)),
+            VotingParameter::MaxVotingTokens(Some(MaxTokens::get() + 1)),
+            VotingParameter::MaxProposals(Some(0)),
+            VotingParameter::MaxProposals(Some(MaxProposals::get() + 1)),
+        ] {
+            assert_noop!(
+                VotingModule::set_parameter(RuntimeOrigin::root(), parameter),
+                Error::<Test>::InvalidParameter
+            );
+        }
+
+        let parameter = VotingParameter::MaxProposals(Some(MaxProposals::get()));
+        assert_ok!(VotingModule::set_parameter(
+            RuntimeOrigin::root(),
+            parameter
+        ));
+        System::assert_last_event(Event::ParameterSet { parameter }.into());
+    });
+}
+
+#[test]
+fn entry_fee_and_credits_are_read_on_join() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (bob, charlie) = (get_bob(), get_charlie());
+        join(&bob);
+        let fee = EntryFee::get() / 10;
+        assert_ok!(VotingModule::set_parameter(
+            RuntimeOrigin::root(),
+            VotingParameter::EntryFee(Some(fee))
+        ));
+        assert_ok!(VotingModule::set_parameter(
+            RuntimeOrigin::root(),
+            VotingParameter::MaxVotingTokens(Some(50))
+        ));
+
+        // charlie could not afford the configured entry fee
+        join(&charlie);
+        assert_eq!(Balances::reserved_balance(&charlie), fee);
+        assert_eq!(
+            <Members<Test>>::get(&charlie),
+            VoterBalance {
+                voting_tokens: 50,
+                reserved_balance: fee,
+            }
+        );
+        // members keep the deposit they paid
+        assert_eq!(Balances::reserved_balance(&bob), EntryFee::get());
+
+        assert_ok!(VotingModule::set_parameter(
+            RuntimeOrigin::root(),
+            VotingParameter::EntryFee(None)
+        ));
+        assert_eq!(VotingModule::entry_fee(), EntryFee::get());
+    });
+}
+
+#[test]
+fn lengths_and_proposals_are_read_at_call_time() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        join(&alice);
+        let origin = RuntimeOrigin::signed(alice.clone());
+        for parameter in [
+            VotingParameter::MinLength(Some(200)),
+            VotingParameter::RevealLength(Some(10)),
+            VotingParameter::MaxProposals(Some(1)),
+        ] {
+            assert_ok!(VotingModule::set_parameter(
+                RuntimeOrigin::root(),
+                parameter
+            ));
+        }
+
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(0)),
+                target(),
+                100,
+                None,
+                None
+            ),
+            Error::<Test>::WrongProposalLength
+        );
+        assert_ok!(VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(0)),
+            target(),
+            200,
+            None,
+            None
+        ));
+        assert_noop!(
+            VotingModule::create_proposal(origin, Box::new(note(1)), target(), 200, None, None),
+            Error::<Test>::TooManyProposals
+        );
+
+        let proposal_hash = <Proposals<Test>>::get()[0];
+        run_to_block(201);
+        assert_eq!(
+            <ProposalData<Test>>::get(proposal_hash).unwrap().reveal_end,
+            Some(211)
+        );
+    });
+}
+
+#[test]
+fn full_deadline_queue_fails_the_call() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        join(&alice);
+        let origin = RuntimeOrigin::signed(alice.clone());
+        let full: Vec<_> = (0..MaxDeadlines::<Test>::get())
+            .map(|i| (H256::repeat_byte(i as u8), Phase::Archived))
+            .collect();
+        <Deadlines<Test>>::insert(101, BoundedVec::truncate_from(full.clone()));
+        <Deadlines<Test>>::insert(152, BoundedVec::truncate_from(full));
+
+        assert_noop!(
+            VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(1)),
+                target(),
+                100,
+                None,
+                None,
+            ),
+            Error::<Test>::TooManyDeadlines
+        );
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(1)),
+            target(),
+            101,
+            None,
+            None,
+        ));
+        let proposal = <Proposals<Test>>::get()[0];
+
+        // the commit phase cannot close while its reveal deadline does not fit
+        System::set_block_number(102);
+        assert_noop!(
+            VotingModule::close_vote(RuntimeOrigin::signed(alice), proposal),
+            Error::<Test>::TooManyDeadlines
+        );
+        assert_eq!(
+            <ProposalData<Test>>::get(proposal).unwrap().reveal_end,
+            None
+        );
+    });
+}
+
+#[test]
+fn closes_past_the_cap_are_carried_over() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        join(&alice);
+        let origin = RuntimeOrigin::signed(alice);
+        let count = MaxClosesPerBlock::get() * 2;
+        for i in 0..count as u8 {
+            assert_ok!(VotingModule::create_proposal(
+                origin.clone(),
+                Box::new(note(i)),
+                target(),
+                100,
+                None,
+                None
+            ));
+        }
+        // one more phase ends in the next block
+        assert_ok!(VotingModule::create_proposal(
+            origin,
+            Box::new(note(count as u8)),
+            target(),
+            101,
+            None,
+            None
+        ));
+        let proposals = <Proposals<Test>>::get();
+        let revealing = || {
+            proposals
+                .iter()
+                .filter(|p| <ProposalData<Test>>::get(p).unwrap().reveal_end.is_some())
+                .count() as u32
+        };
+
+        run_to_block(101);
+        assert_eq!(revealing(), MaxClosesPerBlock::get());
+        assert_eq!(<DeadlineCursor<Test>>::get(), Some(101));
+        assert_eq!(
+            <Deadlines<Test>>::get(101).len() as u32,
+            count - MaxClosesPerBlock::get()
+        );
+
+        // the phases carried over are closed before the ones of the block
+        run_to_block(102);
+        assert_eq!(revealing(), MaxClosesPerBlock::get() * 2);
+        assert_eq!(<DeadlineCursor<Test>>::get(), Some(102));
+        assert!(<Deadlines<Test>>::get(101).is_empty());
+        assert_eq!(
+            <Deadlines<Test>>::get(102).into_inner(),
+            vec![(proposals[count as usize], Phase::Commit)]
+        );
+
+        run_to_block(103);
+        assert_eq!(revealing(), count + 1);
+        assert_eq!(<DeadlineCursor<Test>>::get(), None);
+        assert!(<Deadlines<Test>>::get(102).is_empty());
+    });
+}
+
+#[test]
+fn failed_auto_close_is_reported_and_retried() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let alice = get_alice();
+        join(&alice);
+        let origin = RuntimeOrigin::signed(alice.clone());
+        assert_ok!(VotingModule::create_proposal(
+            origin.clone(),
+            Box::new(note(1)),
+            target(),
+            100,
+            None,
+            None
+        ));
+        let proposal = <Proposals<Test>>::get()[0];
+        // the reveal phase cannot end in a full block, whose deadlines are all
+        // stale
+        let reveal_end = 101 + RevealLength::get();
+        let full: Vec<_> = (0..MaxDeadlines::<Test>::get())
+            .map(|i| (H256::repeat_byte(i as u8), Phase::Archived))
+            .collect();
+        <Deadlines<Test>>::insert(reveal_end, BoundedVec::truncate_from(full));
+
+        run_to_block(101);
+        let retry = 101 + MinLength::get();
+        System::assert_has_event(
+            Event::<Test>::AutoCloseFailed {
+                proposal_hash: proposal,
+                phase: Phase::Commit,
+                error: Error::<Test>::TooManyDeadlines.into(),
+                retry_at: Some(retry),
+            }
+            .into(),
+        );
+        assert_eq!(
+            <ProposalData<Test>>::get(proposal).unwrap().reveal_end,
+            None
+        );
+        assert_eq!(
+            <Deadlines<Test>>::get(retry).into_inner(),
+            vec![(proposal, Phase::Commit)]
+        );
+
+        // it can still be closed manually, and the retry is then skipped
+        run_to_block(102);
+        assert_ok!(VotingModule::close_vote(origin, proposal));
+        run_to_block(retry);
+        assert!(<Deadlines<Test>>::get(retry).is_empty());
+        assert_eq!(
+            System::events()
+                .iter()
+                .filter(|record| matches!(
+                    record.event,
+                    RuntimeEvent::VotingModule(Event::AutoCloseFailed { .. })
+                ))
+                .count(),
+            1
+        );
+        assert!(<ProposalResults<Test>>::contains_key(proposal));
+    });
+}
+
+/// Overwrites a proposal with its layout before v4.
+fn put_v3_proposal(proposal_hash: &H256, ayes: u32, nays: u32, closed: bool) {
+    let proposal = <ProposalData<Test>>::get(proposal_hash).unwrap();
+    let old = v4::OldProposal {
+        title: proposal.title,
+        proposer: proposal.proposer,
+        ayes,
+        nays,
+        poll_end: proposal.poll_end,
+        reveal_end: closed.then_some(150),
+        votes: Vec::new(),
+        revealed: Vec::new(),
+        payout: proposal.payout,
+        closed,
+    };
+    frame_support::storage::unhashed::put(
+        &<ProposalData<Test>>::hashed_key_for(proposal_hash),
+        &old,
+    );
+}
+
+fn note(i: u8) -> Data { Data::Raw(vec![i].try_into().unwrap()) }
+
+/// The post most notes in these tests annotate.
+fn target() -> NoteTargetOf<Test> {
+    NoteTarget {
+        platform: b"x.com".to_vec().try_into().unwrap(),
+        content: H256::repeat_byte(1),
+        timestamp: None,
+    }
+}
+
+fn data() -> IdentityInfo<MaxAdditionalFields> { identity_info(b"ten") }
+
+/// Sets an identity for `who` and makes it a member.
+/// Registers a keystore holding an importer key with `ext` and returns the key
+fn importer_key(ext: &mut sp_io::TestExternalities) -> sr25519::Public {
+    let keystore = MemoryKeystore::new();
+    let key = keystore.sr25519_generate_new(offchain::KEY_TYPE, None).unwrap();
+    ext.register_extension(KeystoreExt::new(keystore));
+    key
+}
+
+fn importer_account(key: sr25519::Public) -> AccountId { MultiSigner::from(key).into_account() }
+
+/// An import of `posts` by `key`, for the next import of the feed
+fn import_payload(key: sr25519::Public, posts: Vec<NoteTargetOf<Test>>) -> ImportPayloadOf<Test> {
+    ImportPayload {
+        candidates: ImportBatchOf::<Test>::truncate_from(posts),
+        import_at: <NextImportAt<Test>>::get(),
+        public: key.into(),
+    }
+}
+
+/// A signature the call does not check, as it is only checked when the
+/// transaction is validated
+fn unchecked_signature() -> MultiSignature { sr25519::Signature::from_raw([0; 64]).into() }
+
+/// Imports `posts` on behalf of an importer
+fn import(posts: Vec<NoteTargetOf<Test>>) -> DispatchResult {
+    let key = sr25519::Public::from_raw([1; 32]);
+    <Importers<Test>>::insert(importer_account(key), ());
+    VotingModule::submit_candidates(
+        RuntimeOrigin::none(),
+        import_payload(key, posts),
+        unchecked_signature(),
+    )
+}
+
+/// A post of the feed other than [`target`]
+fn target_of(i: u8) -> NoteTargetOf<Test> {
+    let mut post = target();
+    post.content = H256::repeat_byte(i);
+    post
+}
+
+fn join(who: &AccountId) {
+    let origin = RuntimeOrigin::signed(who.clone());
+    assert_ok!(Identity::set_identity(origin.clone(), Box::new(data())));
+    assert_ok!(VotingModule::join_committee(origin));
 }
@@ -1,67 +1,406 @@
 use codec::Decode;
 use codec::Encode;
 use codec::MaxEncodedLen;
+use frame_support::sp_runtime::Permill;
 use frame_support::sp_runtime::RuntimeDebug;
 use frame_support::traits::ConstU32;
 use frame_support::BoundedVec;
 use scale_info::prelude::vec::Vec;
 use scale_info::TypeInfo;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
-pub type VoteToken = u8;
+/// Index of a rating option of a proposal.
+pub type OptionIndex = u8;
+
+/// Maximum number of rating options a proposal can declare.
+pub const MAX_OPTIONS: u32 = 8;
+
+/// Human readable name of a rating option.
+pub type RatingLabel = BoundedVec<u8, ConstU32<64>>;
+
+/// The rating options of a proposal, voted on by index.
+pub type RatingOptions = BoundedVec<RatingLabel, ConstU32<MAX_OPTIONS>>;
+
+/// Total votes given to each rating option.
+pub type Tally = BoundedVec<u32, ConstU32<MAX_OPTIONS>>;
+
+/// Share of the votes given to each rating option.
+pub type Breakdown = BoundedVec<Permill, ConstU32<MAX_OPTIONS>>;
+
+/// Options with the most votes. More than one means a tie.
+pub type Winners = BoundedVec<OptionIndex, ConstU32<MAX_OPTIONS>>;
+
+/// The rating options of Community Notes, used when a proposal does not
+/// declare its own. The first option is the one approving the note.
+pub const DEFAULT_OPTIONS: [&[u8]; 3] = [b"Helpful", b"Somewhat Helpful", b"Not Helpful"];
+
+/// The rating option approving the note.
+pub const APPROVING_OPTION: OptionIndex = 0;
+
+/// Viewpoint cluster of a member, grouping raters who tend to agree.
+pub type Viewpoint = u8;
+
+/// Number of viewpoint clusters members can be assigned to.
+pub const MAX_VIEWPOINTS: Viewpoint = 16;
+
+/// Secret blinding factor of a commitment. It is only disclosed on reveal.
+pub type Salt = [u8; 32];
 
 #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Data {
     /// The data is stored directly.
     Raw(BoundedVec<u8, ConstU32<2048>>),
 }
 
+/// Identifier of the platform hosting annotated content, e.g. `x.com`.
+pub type Platform = BoundedVec<u8, ConstU32<32>>;
+
+/// What a delegation covers: the notes about content hosted on a platform, or
+/// every note when `None`.
+pub type Topic = Option<Platform>;
+
+/// Maximum number of topics a member can delegate separately.
+pub const MAX_DELEGATIONS: u32 = 8;
+
+/// The delegate of a member for each topic it delegates.
+pub type Delegates<AccountId> = BoundedVec<(Topic, AccountId), ConstU32<MAX_DELEGATIONS>>;
+
+/// The external content a note annotates, such as a post, a comment or a URL.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NoteTarget<Hash> {
+    /// The platform hosting the content
+    pub platform: Platform,
+    /// Hash of the id or the URL of the content on the platform
+    pub content: Hash,
+    /// When the content was published, in seconds since the Unix epoch
+    pub timestamp: Option<u64>,
+}
+
+/// A post imported from the feed by the offchain worker, waiting for a member
+/// to propose a note about it.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Candidate<Hash, BlockNumber> {
+    /// The imported content
+    pub target: NoteTarget<Hash>,
+    /// The block the content was imported at
+    pub imported_at: BlockNumber,
+}
+
 #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub struct Proposal<AccountId, BlockNumberFor, Balance> {
     /// The title of community note.
     pub title: Data,
     /// Who proposed
     pub proposer: AccountId,
-    /// Total votes for proposal to pass
-    pub ayes: u32,
-    /// Total votes for proposal to get rejected
-    pub nays: u32,
+    /// The options voters rate the note with
+    pub options: RatingOptions,
+    /// Total votes for each option
+    pub tally: Tally,
     /// The hard end of voting phase
     pub poll_end: BlockNumberFor,
     /// The hard end of reveal phase
     pub reveal_end: Option<BlockNumberFor>,
-    /// The number of votes each voter gave
-    pub votes: Vec<(AccountId, u8, Vote)>,
-    /// Users who revealed their choices.
-    /// Allows to verify who did not reveal on time
-    pub revealed: Vec<AccountId>,
+    /// The number of voters who committed, bounded by `MaxVotersPerProposal`
+    pub voters: u32,
+    /// The number of voters who revealed their choices
+    pub revealed: u32,
     /// The amount that was slashed and distributed
     pub payout: Balance,
     /// Is proposal closed
     pub closed: bool,
 }
 
-#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
-pub enum Vote {
-    Yes,
-    No,
+/// The outcome of a closed proposal.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Outcome {
+    Approved,
+    Disapproved,
+    Tie,
 }
 
-#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
-pub struct Commit<Signature> {
-    /// The signed choice of a voter
-    pub signature: Signature,
-    /// The number of votes the voter gives to their choice.
-    /// Must be exposed and unencrypted to allow double spend of votes
-    pub number: u8,
-    /// Salt which comes with the choice to ensure the security
-    pub salt: u32,
+impl Outcome {
+    /// Deduces the outcome from the winning options. The note is approved
+    /// only if the first option wins on its own.
+    pub fn of(winners: &[OptionIndex]) -> Self {
+        match winners {
+            [APPROVING_OPTION] => Outcome::Approved,
+            [_] => Outcome::Disapproved,
+            _ => Outcome::Tie,
+        }
+    }
 }
 
-#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, Default)]
-pub struct VoterBalance<Balance> {
+/// Builds the default rating options.
+pub fn default_options() -> RatingOptions {
+    let options: Vec<RatingLabel> = DEFAULT_OPTIONS
+        .iter()
+        .map(|label| RatingLabel::truncate_from(label.to_vec()))
+        .collect();
+    RatingOptions::truncate_from(options)
+}
+
+/// Returns the options with the most votes. Nothing wins if no votes were
+/// given.
+pub fn winners(tally: &[u32]) -> Winners {
+    let top = tally.iter().copied().max().unwrap_or_default();
+    if top == 0 {
+        return Winners::default();
+    }
+    let winners: Vec<OptionIndex> = tally
+        .iter()
+        .enumerate()
+        .filter(|(_, votes)| **votes == top)
+        .map(|(index, _)| index as OptionIndex)
+        .collect();
+    Winners::truncate_from(winners)
+}
+
+/// Computes the share of the votes given to each option.
+pub fn breakdown(tally: &[u32]) -> Breakdown {
+    let total = tally.iter().fold(0u32, |total, votes| total.saturating_add(*votes));
+    let shares: Vec<Permill> = tally
+        .iter()
+        .map(|votes| {
+            if total == 0 {
+                Permill::zero()
+            } else {
+                Permill::from_rational(*votes, total)
+            }
+        })
+        .collect();
+    Breakdown::truncate_from(shares)
+}
+
+/// What is kept of a proposal once it is closed.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProposalResult<BlockNumber, Balance> {
+    /// The winning side
+    pub outcome: Outcome,
+    /// The options with the most votes
+    pub winners: Winners,
+    /// Total votes for each option
+    pub tally: Tally,
+    /// Share of the votes given to each option
+    pub breakdown: Breakdown,
+    /// The amount that was slashed and distributed
+    pub payout: Balance,
+    /// The block the reveal phase was closed at
+    pub closed_at: BlockNumber,
+}
+
+/// What happens to the voting tokens of a commit that was never revealed.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum TokenPolicy {
+    /// The tokens are given back to the voter
+    Release,
+    /// The tokens are lost
+    Burn,
+}
+
+/// The index of a credit epoch, the number of whole epochs since genesis.
+pub type Epoch = u32;
+
+/// How members get back the voting credits they spent.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum CreditPolicy<BlockNumber, Credit> {
+    /// The credits spent on a proposal are refunded when it is closed
+    RefundOnClose,
+    /// Nothing is refunded, the credits are replenished every `length`
+    /// blocks instead. At most `carry_over` unspent credits are kept, all of
+    /// them if `None`, and `grant` credits are added up to `MaxVotingTokens`.
+    Epochs {
+        length: BlockNumber,
+        grant: Credit,
+        carry_over: Option<Credit>,
+    },
+}
+
+/// A voting parameter set on chain. `None` restores the value of the runtime
+/// configuration.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum VotingParameter<Balance, BlockNumber, Credit> {
+    /// The deposit reserved on joining the committee
+    EntryFee(Option<Balance>),
+    /// The length of the reveal phase
+    RevealLength(Option<BlockNumber>),
+    /// The minimum length of the commit phase of a proposal
+    MinLength(Option<BlockNumber>),
+    /// The credits a member is given on joining, and the most they can hold,
+    /// up to `MaxVotingTokens`
+    MaxVotingTokens(Option<Credit>),
+    /// The number of active proposals, up to `MaxProposals`
+    MaxProposals(Option<u32>),
+}
+
+/// The voting parameters set on chain, each overriding the runtime
+/// configuration when it is `Some`.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen, Default)]
+pub struct VotingParameters<Balance, BlockNumber, Credit> {
+    pub entry_fee: Option<Balance>,
+    pub reveal_length: Option<BlockNumber>,
+    pub min_length: Option<BlockNumber>,
+    pub max_voting_tokens: Option<Credit>,
+    pub max_proposals: Option<u32>,
+}
+
+/// How well registrars vouch for an identity, from worst to best. Negative
+/// judgements count as `Unknown`.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    RuntimeDebug,
+    Encode,
+    Decode,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum IdentityJudgement {
+    /// The identity has no positive judgement
+    Unknown,
+    /// A registrar found the identity reasonable
+    Reasonable,
+    /// A registrar knows the identity to be good
+    KnownGood,
+}
+
+/// A phase of a proposal with a deadline.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Phase {
+    /// Voters submit their commitments
+    Commit,
+    /// Voters disclose their votes
+    Reveal,
+    /// The outcome is kept until the end of the retention period
+    Archived,
+}
+
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct Commit<Hash, VoteCredit> {
+    /// Blinded choice of a voter, `hash(option, salt, voter, proposal_hash)`.
+    /// The salt stays private until the reveal phase.
+    pub commitment: Hash,
     /// The number of votes the voter gives to their choice.
     /// Must be exposed and unencrypted to allow double spend of votes
-    pub voting_tokens: VoteToken,
+    pub number: VoteCredit,
+}
+
+/// A revealed vote of a voter on a proposal.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct VoteRecord<VoteCredit> {
+    /// The number of votes the voter gave
+    pub number: VoteCredit,
+    /// The option the votes were given to
+    pub option: OptionIndex,
+}
+
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VoterBalance<Balance, VoteCredit> {
+    /// The credits left to the voter. Committing `n` votes costs `n²`
+    /// credits.
+    pub voting_tokens: VoteCredit,
     /// Salt which comes with the choice to ensure the security
     pub reserved_balance: Balance,
 }
+
+/// The track record of a member, kept after they leave the committee.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Reputation<Balance> {
+    /// Closed proposals the member revealed a vote on in time
+    pub participated: u32,
+    /// Of those, the proposals where the member voted for the single winning
+    /// option
+    pub aligned: u32,
+    /// Votes revealed after the reveal phase was closed
+    pub late_reveals: u32,
+    /// Commits that were never revealed and got reaped
+    pub unrevealed: u32,
+    /// Funds slashed from the member in total
+    pub slashed: Balance,
+    /// Funds paid to the member from the pot in total
+    pub rewarded: Balance,
+}
+
+/// An active proposal as exposed to clients.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProposalInfo<AccountId, BlockNumber, Hash> {
+    /// The hash identifying the proposal
+    pub proposal_hash: Hash,
+    /// The title of community note.
+    pub title: Data,
+    /// The content the note annotates, unknown for notes proposed before
+    /// targets were introduced
+    pub target: Option<NoteTarget<Hash>>,
+    /// Who proposed
+    pub proposer: AccountId,
+    /// The phase the proposal is in
+    pub phase: Phase,
+    /// The hard end of voting phase
+    pub poll_end: BlockNumber,
+    /// The hard end of reveal phase
+    pub reveal_end: Option<BlockNumber>,
+    /// The options voters rate the note with
+    pub options: RatingOptions,
+    /// Total votes for each option, known once the reveal phase started
+    pub tally: Option<Tally>,
+    /// The number of voters who revealed
+    pub revealed: u32,
+}
+
+/// What an account would be rewarded and slashed if a proposal was closed
+/// with the votes revealed so far.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PayoutEstimate<Balance> {
+    /// The share of the pot paid to the account
+    pub reward: Balance,
+    /// The reserved funds the account loses
+    pub slash: Balance,
+}
+
+/// Bookkeeping of the funds slashed into the pot. Everything that came in is
+/// either distributed, burned, or still held as dust or unclaimed funds.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PotLedger<Balance> {
+    /// Funds slashed into the pot
+    pub inflow: Balance,
+    /// Funds paid out to voters, as rewards or redistributed by governance
+    pub distributed: Balance,
+    /// Funds burned by governance
+    pub burned: Balance,
+    /// Rounding remainders of rewards shared pro rata
+    pub dust: Balance,
+    /// Slashes nobody was rewarded with: proposals without a rewarded side,
+    /// late reveals, unrevealed commits and bonds
+    pub unclaimed: Balance,
+}
+
+impl<Balance: frame_support::sp_runtime::Saturating + Copy> PotLedger<Balance> {
+    /// The funds governance can redistribute or burn
+    pub fn available(&self) -> Balance { self.dust.saturating_add(self.unclaimed) }
+}
+
+/// What governance does with funds held in the pot.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum PotSpend<Beneficiaries> {
+    /// Removes the funds from the total issuance
+    Burn,
+    /// Shares the funds equally between the given members, as rewards
+    Redistribute(Beneficiaries),
+}
@@ -1,3 +1,19 @@
+//! Weights for pallet_voting
+//!
+//! Generated with the `benchmark pallet` command of frame-benchmarking-cli
+//! 43.0.0 on 2026-10-18, with `STEPS: 50` and `REPEAT: 20`,
+//! on `Intel(R) Xeon(R) Processor`. The proof sizes are estimated from the
+//! maximum encoded length of the storage items, with `WORST CASE MAP SIZE:
+//! 1000000`. Regenerate them with:
+//!
+//! ```sh
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/harvest-moon-runtime/harvest_moon_runtime.wasm \
+//!     --pallet pallet_voting --extrinsic "*" --steps 50 --repeat 20 \
+//!     --template .maintain/frame-weight-template.hbs \
+//!     --output pallets/voting/src/weights.rs
+//! ```
+
 #![allow(unused_parens)]
 #![allow(unused_imports)]
 
@@ -10,130 +26,1858 @@ use frame_support::weights::Weight;
 /// Weight functions needed for pallet_voting.
 pub trait WeightInfo {
     fn join_committee() -> Weight;
-    fn leave_committee() -> Weight;
-    fn create_proposal() -> Weight;
-    fn cause_error() -> Weight;
+    fn leave_committee(d: u32) -> Weight;
+    fn create_proposal(p: u32) -> Weight;
     fn close_vote() -> Weight;
-    fn close_reveal() -> Weight;
     fn commit_vote() -> Weight;
-    fn reveal_vote() -> Weight;
+    fn reveal_vote(v: u32) -> Weight;
+    fn reveal_delegated(d: u32) -> Weight;
+    fn close_reveal(v: u32) -> Weight;
+    fn prune_result() -> Weight;
+    fn reap_unrevealed(n: u32) -> Weight;
+    fn spend_pot(b: u32) -> Weight;
+    fn refresh_judgement() -> Weight;
+    fn declare_viewpoint(p: u32) -> Weight;
+    fn set_viewpoint(p: u32) -> Weight;
+    fn submit_candidates(n: u32) -> Weight;
+    fn expire_candidate() -> Weight;
+    fn set_importer() -> Weight;
+    fn dismiss_candidate() -> Weight;
+    fn delegate(c: u32) -> Weight;
+    fn undelegate() -> Weight;
+    fn replenish_credits() -> Weight;
+    fn withdraw_proposal(p: u32) -> Weight;
+    fn cancel_proposal(v: u32) -> Weight;
+    fn refund_cancelled(v: u32) -> Weight;
+    fn force_remove_member(p: u32, d: u32) -> Weight;
+    fn force_close_vote() -> Weight;
+    fn force_close_reveal(v: u32) -> Weight;
+    fn force_set_member_balance() -> Weight;
+    fn set_parameter() -> Weight;
 }
 
 /// Weights for pallet_voting using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-    /// Storage: VotingModule Something (r:0 w:1)
-    /// Proof: VotingModule Something (max_values: Some(1), max_size: Some(4),
-    /// added: 499, mode: MaxEncodedLen)
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Identity::IdentityOf` (r:1 w:0)
+    /// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`:
+    /// Some(7572), added: 10047, mode: `MaxEncodedLen`)
+    /// Storage: `Identity::SuperOf` (r:1 w:0)
+    /// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114),
+    /// added: 2589, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForMembers` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForMembers` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CreditEpochs` (r:0 w:1)
+    /// Proof: `VotingModule::CreditEpochs` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
     fn join_committee() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `491`
+        //  Estimated: `11037`
+        // Minimum execution time: 125_346_000 picoseconds.
+        Weight::from_parts(139_254_000, 11037)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Identity::IdentityOf` (r:1 w:0)
+    /// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`:
+    /// Some(7572), added: 10047, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForMembers` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForMembers` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:33 w:33)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:9 w:9)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ViewpointChanges` (r:0 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CreditEpochs` (r:0 w:1)
+    /// Proof: `VotingModule::CreditEpochs` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:0 w:1)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`) The range of component
+    /// `d` is `[0, 40]`.
+    fn leave_committee(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1164 + d * (136 ±0)`
+        //  Estimated: `27310 + d * (2137 ±29)`
+        // Minimum execution time: 126_626_000 picoseconds.
+        Weight::from_parts(165_097_397, 27310)
+            // Standard Error: 185_035
+            .saturating_add(Weight::from_parts(18_525_560, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 2137).saturating_mul(d.into()))
+    }
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CancelledProposals` (r:1 w:0)
+    /// Proof: `VotingModule::CancelledProposals` (`max_values`: None,
+    /// `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Candidates` (r:1 w:1)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:0 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`) The range of
+    /// component `p` is `[1, 10]`.
+    fn create_proposal(_p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `487 + p * (54 ±0)`
+        //  Estimated: `4138`
+        // Minimum execution time: 169_086_000 picoseconds.
+        Weight::from_parts(211_958_884, 4138)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    fn close_vote() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `579`
+        //  Estimated: `6176`
+        // Minimum execution time: 68_443_000 picoseconds.
+        Weight::from_parts(71_072_000, 6176)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:1)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:0 w:1)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    fn commit_vote() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `580`
+        //  Estimated: `6176`
+        // Minimum execution time: 76_556_000 picoseconds.
+        Weight::from_parts(81_106_000, 6176)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:1)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:1 w:1)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:0)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:1 w:0)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:0 w:1)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`) The range of component
+    /// `v` is `[0, 255]`.
+    fn reveal_vote(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1802 + v * (3 ±0)`
+        //  Estimated: `6176`
+        // Minimum execution time: 85_309_000 picoseconds.
+        Weight::from_parts(147_798_416, 6176)
+            // Standard Error: 18_309
+            .saturating_add(Weight::from_parts(235_852, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `VotingModule::Members` (r:33 w:32)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:33 w:1)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:33 w:33)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:0)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:33 w:0)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:32 w:0)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:32 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:0 w:1)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`) The range of component
+    /// `d` is `[0, 32]`.
+    fn reveal_delegated(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `864 + d * (163 ±0)`
+        //  Estimated: `6176 + d * (4620 ±0)`
+        // Minimum execution time: 80_770_000 picoseconds.
+        Weight::from_parts(132_052_314, 6176)
+            // Standard Error: 393_084
+            .saturating_add(Weight::from_parts(54_210_406, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4620).saturating_mul(d.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:257 w:256)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:257 w:256)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:256 w:256)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:258 w:258)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:1 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:1 w:0)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`) The range of
+    /// component `v` is `[1, 256]`.
+    fn close_reveal(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `644 + v * (232 ±0)`
+        //  Estimated: `19885 + v * (2605 ±0)`
+        // Minimum execution time: 253_064_000 picoseconds.
+        Weight::from_parts(861_208_231, 19885)
+            // Standard Error: 1_641_562
+            .saturating_add(Weight::from_parts(99_151_215, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes(9_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2605).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::ProposalResults` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:0 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn prune_result() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `442`
+        //  Estimated: `3593`
+        // Minimum execution time: 36_939_000 picoseconds.
+        Weight::from_parts(38_055_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CancelledProposals` (r:1 w:0)
+    /// Proof: `VotingModule::CancelledProposals` (`max_values`: None,
+    /// `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:64 w:64)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:64 w:64)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:64 w:64)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:65 w:65)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:64 w:64)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:1 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`) The range of component `n` is
+    /// `[1, 64]`.
+    fn reap_unrevealed(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2756 + n * (340 ±0)`
+        //  Estimated: `6176 + n * (2603 ±0)`
+        // Minimum execution time: 183_735_000 picoseconds.
+        Weight::from_parts(44_938_138, 6176)
+            // Standard Error: 1_905_106
+            .saturating_add(Weight::from_parts(109_301_619, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+    }
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`) Storage: `VotingModule::Members`
+    /// (r:64 w:64) Proof: `VotingModule::Members` (`max_values`: None,
+    /// `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:65 w:65)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:64 w:64)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`) The range of component
+    /// `b` is `[1, 64]`.
+    fn spend_pot(b: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `212 + b * (188 ±0)`
+        //  Estimated: `3593 + b * (2603 ±0)`
+        // Minimum execution time: 78_642_000 picoseconds.
+        Weight::from_parts(83_839_000, 3593)
+            // Standard Error: 522_752
+            .saturating_add(Weight::from_parts(71_005_201, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(b.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Identity::IdentityOf` (r:1 w:0)
+    /// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`:
+    /// Some(7572), added: 10047, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:1)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn refresh_judgement() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `551`
+        //  Estimated: `11037`
+        // Minimum execution time: 55_478_000 picoseconds.
+        Weight::from_parts(59_588_000, 11037)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `VotingModule::ViewpointChanges` (r:1 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:10 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`) The range of component
+    /// `p` is `[1, 10]`.
+    fn declare_viewpoint(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `570 + p * (32 ±0)`
+        //  Estimated: `3565 + p * (2544 ±0)`
+        // Minimum execution time: 58_281_000 picoseconds.
+        Weight::from_parts(80_211_882, 3565)
+            // Standard Error: 62_483
+            .saturating_add(Weight::from_parts(4_362_281, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 2544).saturating_mul(p.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:10 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ViewpointChanges` (r:0 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`) The range of component
+    /// `p` is `[1, 10]`.
+    fn set_viewpoint(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `512 + p * (32 ±0)`
+        //  Estimated: `3565 + p * (2544 ±0)`
+        // Minimum execution time: 59_159_000 picoseconds.
+        Weight::from_parts(65_283_037, 3565)
+            // Standard Error: 52_283
+            .saturating_add(Weight::from_parts(5_288_606, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 2544).saturating_mul(p.into()))
+    }
+    /// Storage: `VotingModule::NextImportAt` (r:1 w:1)
+    /// Proof: `VotingModule::NextImportAt` (`max_values`: Some(1), `max_size`:
+    /// Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Importers` (r:1 w:0)
+    /// Proof: `VotingModule::Importers` (`max_values`: None, `max_size`:
+    /// Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForCandidates` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForCandidates` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Candidates` (r:32 w:32)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:32 w:0)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`) The range of component
+    /// `n` is `[1, 32]`.
+    fn submit_candidates(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `157`
+        //  Estimated: `3497 + n * (2585 ±0)`
+        // Minimum execution time: 57_344_000 picoseconds.
+        Weight::from_parts(31_829_297, 3497)
+            // Standard Error: 77_769
+            .saturating_add(Weight::from_parts(30_004_853, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2585).saturating_mul(n.into()))
+    }
+    /// Storage: `VotingModule::Candidates` (r:1 w:1)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForCandidates` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForCandidates` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn expire_candidate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `256`
+        //  Estimated: `3575`
+        // Minimum execution time: 37_318_000 picoseconds.
+        Weight::from_parts(41_010_000, 3575)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Importers` (r:0 w:1)
+    /// Proof: `VotingModule::Importers` (`max_values`: None, `max_size`:
+    /// Some(32), added: 2507, mode: `MaxEncodedLen`)
+    fn set_importer() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+        // Minimum execution time: 14_878_000 picoseconds.
+        Weight::from_parts(18_991_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
     }
-
-    fn leave_committee() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Candidates` (r:1 w:1)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForCandidates` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForCandidates` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn dismiss_candidate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `356`
+        //  Estimated: `3575`
+        // Minimum execution time: 53_324_000 picoseconds.
+        Weight::from_parts(58_886_000, 3575)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
     }
-
-    fn create_proposal() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: `VotingModule::Members` (r:2 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:4 w:1)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:1 w:1)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`) The range of
+    /// component `c` is `[1, 3]`.
+    fn delegate(c: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `95 + c * (208 ±0)`
+        //  Estimated: `6044 + c * (3036 ±0)`
+        // Minimum execution time: 58_941_000 picoseconds.
+        Weight::from_parts(57_168_494, 6044)
+            // Standard Error: 201_289
+            .saturating_add(Weight::from_parts(12_346_097, 0).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 3036).saturating_mul(c.into()))
     }
-
-    fn close_vote() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: `VotingModule::Delegations` (r:1 w:1)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:1 w:1)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    fn undelegate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `310`
+        //  Estimated: `5610`
+        // Minimum execution time: 43_963_000 picoseconds.
+        Weight::from_parts(49_378_000, 5610)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
     }
-
-    fn close_reveal() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn replenish_credits() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `246`
+        //  Estimated: `3517`
+        // Minimum execution time: 24_729_000 picoseconds.
+        Weight::from_parts(27_658_000, 3517).saturating_add(T::DbWeight::get().reads(1_u64))
     }
-
-    fn commit_vote() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`) The range of
+    /// component `p` is `[1, 10]`.
+    fn withdraw_proposal(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `677 + p * (124 ±0)`
+        //  Estimated: `6176`
+        // Minimum execution time: 112_905_000 picoseconds.
+        Weight::from_parts(134_267_010, 6176)
+            // Standard Error: 115_012
+            .saturating_add(Weight::from_parts(2_901_568, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
     }
-
-    fn reveal_vote() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:65 w:64)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:64 w:64)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:64 w:64)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:1 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`) The range of
+    /// component `v` is `[0, 64]`.
+    fn cancel_proposal(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `941 + v * (201 ±0)`
+        //  Estimated: `6176 + v * (2575 ±0)`
+        // Minimum execution time: 158_548_000 picoseconds.
+        Weight::from_parts(181_209_605, 6176)
+            // Standard Error: 158_770
+            .saturating_add(Weight::from_parts(34_571_672, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2575).saturating_mul(v.into()))
     }
-
-    /// Storage: VotingModule Something (r:1 w:1)
-    /// Proof: VotingModule Something (max_values: Some(1), max_size: Some(4),
-    /// added: 499, mode: MaxEncodedLen)
-    fn cause_error() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `32`
-        //  Estimated: `1489`
-        // Minimum execution time: 6_000_000 picoseconds.
-        Weight::from_parts(6_000_000, 1489)
+    /// Storage: `VotingModule::CancelledProposals` (r:1 w:1)
+    /// Proof: `VotingModule::CancelledProposals` (`max_values`: None,
+    /// `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:65 w:64)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:64 w:64)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:64 w:64)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:1 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`) The range of component `v` is
+    /// `[1, 64]`.
+    fn refund_cancelled(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `435 + v * (201 ±0)`
+        //  Estimated: `3534 + v * (2575 ±0)`
+        // Minimum execution time: 104_313_000 picoseconds.
+        Weight::from_parts(62_834_188, 3534)
+            // Standard Error: 512_588
+            .saturating_add(Weight::from_parts(27_793_000, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2575).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:10 w:10)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:10 w:10)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForMembers` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForMembers` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:33 w:33)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:9 w:9)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ViewpointChanges` (r:0 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CreditEpochs` (r:0 w:1)
+    /// Proof: `VotingModule::CreditEpochs` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:0 w:1)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`) The range of component
+    /// `p` is `[0, 10]`. The range of component `d` is `[0, 40]`.
+    fn force_remove_member(p: u32, d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1029 + d * (125 ±0) + p * (272 ±0)`
+        //  Estimated: `23575 + d * (2552 ±17) + p * (5186 ±66)`
+        // Minimum execution time: 402_895_000 picoseconds.
+        Weight::from_parts(153_314_932, 23575)
+            // Standard Error: 274_703
+            .saturating_add(Weight::from_parts(29_204_690, 0).saturating_mul(p.into()))
+            // Standard Error: 71_212
+            .saturating_add(Weight::from_parts(17_459_400, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 2552).saturating_mul(d.into()))
+            .saturating_add(Weight::from_parts(0, 5186).saturating_mul(p.into()))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:2 w:2)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    fn force_close_vote() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `471`
+        //  Estimated: `7286`
+        // Minimum execution time: 91_383_000 picoseconds.
+        Weight::from_parts(99_824_000, 7286)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:2 w:2)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:257 w:256)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`) Storage: `VotingModule::Members`
+    /// (r:256 w:256) Proof: `VotingModule::Members` (`max_values`: None,
+    /// `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:256 w:256)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:258 w:258)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:1 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:1 w:0)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`) The range of
+    /// component `v` is `[1, 256]`.
+    fn force_close_reveal(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `640 + v * (232 ±0)`
+        //  Estimated: `19885 + v * (2605 ±0)`
+        // Minimum execution time: 260_292_000 picoseconds.
+        Weight::from_parts(97_883_336, 19885)
+            // Standard Error: 1_678_450
+            .saturating_add(Weight::from_parts(98_222_304, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(13_u64))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(v.into())))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2605).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:10 w:0)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:10 w:0)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn force_set_member_balance() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2958`
+        //  Estimated: `52850`
+        // Minimum execution time: 250_075_000 picoseconds.
+        Weight::from_parts(264_757_000, 52850)
+            .saturating_add(T::DbWeight::get().reads(24_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Parameters` (r:1 w:1)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    fn set_parameter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `119`
+        //  Estimated: `1522`
+        // Minimum execution time: 18_645_000 picoseconds.
+        Weight::from_parts(19_488_000, 1522)
             .saturating_add(T::DbWeight::get().reads(1_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 }
 
-// For backwards compatibility and tests
+// For backwards compatibility and tests.
 impl WeightInfo for () {
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Identity::IdentityOf` (r:1 w:0)
+    /// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`:
+    /// Some(7572), added: 10047, mode: `MaxEncodedLen`)
+    /// Storage: `Identity::SuperOf` (r:1 w:0)
+    /// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114),
+    /// added: 2589, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForMembers` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForMembers` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CreditEpochs` (r:0 w:1)
+    /// Proof: `VotingModule::CreditEpochs` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
     fn join_committee() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 8_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+        //  Measured:  `491`
+        //  Estimated: `11037`
+        // Minimum execution time: 125_346_000 picoseconds.
+        Weight::from_parts(139_254_000, 11037)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
-
-    fn leave_committee() -> Weight {
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    /// Storage: `Identity::IdentityOf` (r:1 w:0)
+    /// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`:
+    /// Some(7572), added: 10047, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForMembers` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForMembers` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:33 w:33)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:9 w:9)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ViewpointChanges` (r:0 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CreditEpochs` (r:0 w:1)
+    /// Proof: `VotingModule::CreditEpochs` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:0 w:1)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`) The range of component
+    /// `d` is `[0, 40]`.
+    fn leave_committee(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1164 + d * (136 ±0)`
+        //  Estimated: `27310 + d * (2137 ±29)`
+        // Minimum execution time: 126_626_000 picoseconds.
+        Weight::from_parts(165_097_397, 27310)
+            // Standard Error: 185_035
+            .saturating_add(Weight::from_parts(18_525_560, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 2137).saturating_mul(d.into()))
     }
-
-    fn create_proposal() -> Weight {
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CancelledProposals` (r:1 w:0)
+    /// Proof: `VotingModule::CancelledProposals` (`max_values`: None,
+    /// `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Candidates` (r:1 w:1)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:0 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`) The range of
+    /// component `p` is `[1, 10]`.
+    fn create_proposal(_p: u32) -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 10_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+        //  Measured:  `487 + p * (54 ±0)`
+        //  Estimated: `4138`
+        // Minimum execution time: 169_086_000 picoseconds.
+        Weight::from_parts(211_958_884, 4138)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
     }
-
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
     fn close_vote() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 10_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+        //  Measured:  `579`
+        //  Estimated: `6176`
+        // Minimum execution time: 68_443_000 picoseconds.
+        Weight::from_parts(71_072_000, 6176)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
-
-    fn close_reveal() -> Weight {
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:1)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:0 w:1)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    fn commit_vote() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 10_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+        //  Measured:  `580`
+        //  Estimated: `6176`
+        // Minimum execution time: 76_556_000 picoseconds.
+        Weight::from_parts(81_106_000, 6176)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
-
-    fn commit_vote() -> Weight {
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:1)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:1 w:1)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:0)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:1 w:0)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:0 w:1)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`) The range of component
+    /// `v` is `[0, 255]`.
+    fn reveal_vote(v: u32) -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 10_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+        //  Measured:  `1802 + v * (3 ±0)`
+        //  Estimated: `6176`
+        // Minimum execution time: 85_309_000 picoseconds.
+        Weight::from_parts(147_798_416, 6176)
+            // Standard Error: 18_309
+            .saturating_add(Weight::from_parts(235_852, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
     }
-
-    fn reveal_vote() -> Weight {
+    /// Storage: `VotingModule::Members` (r:33 w:32)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:33 w:1)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:33 w:33)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:0)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:33 w:0)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:32 w:0)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:32 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:0 w:1)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`) The range of component
+    /// `d` is `[0, 32]`.
+    fn reveal_delegated(d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `864 + d * (163 ±0)`
+        //  Estimated: `6176 + d * (4620 ±0)`
+        // Minimum execution time: 80_770_000 picoseconds.
+        Weight::from_parts(132_052_314, 6176)
+            // Standard Error: 393_084
+            .saturating_add(Weight::from_parts(54_210_406, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 4620).saturating_mul(d.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:257 w:256)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:257 w:256)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:256 w:256)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:258 w:258)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:1 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:1 w:0)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`) The range of
+    /// component `v` is `[1, 256]`.
+    fn close_reveal(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `644 + v * (232 ±0)`
+        //  Estimated: `19885 + v * (2605 ±0)`
+        // Minimum execution time: 253_064_000 picoseconds.
+        Weight::from_parts(861_208_231, 19885)
+            // Standard Error: 1_641_562
+            .saturating_add(Weight::from_parts(99_151_215, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes(9_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2605).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::ProposalResults` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:0 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn prune_result() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `442`
+        //  Estimated: `3593`
+        // Minimum execution time: 36_939_000 picoseconds.
+        Weight::from_parts(38_055_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CancelledProposals` (r:1 w:0)
+    /// Proof: `VotingModule::CancelledProposals` (`max_values`: None,
+    /// `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:64 w:64)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:64 w:64)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:64 w:64)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:65 w:65)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:64 w:64)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:1 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`) The range of component `n` is
+    /// `[1, 64]`.
+    fn reap_unrevealed(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2756 + n * (340 ±0)`
+        //  Estimated: `6176 + n * (2603 ±0)`
+        // Minimum execution time: 183_735_000 picoseconds.
+        Weight::from_parts(44_938_138, 6176)
+            // Standard Error: 1_905_106
+            .saturating_add(Weight::from_parts(109_301_619, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+    }
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`) Storage: `VotingModule::Members`
+    /// (r:64 w:64) Proof: `VotingModule::Members` (`max_values`: None,
+    /// `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:65 w:65)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:64 w:64)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`) The range of component
+    /// `b` is `[1, 64]`.
+    fn spend_pot(b: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `212 + b * (188 ±0)`
+        //  Estimated: `3593 + b * (2603 ±0)`
+        // Minimum execution time: 78_642_000 picoseconds.
+        Weight::from_parts(83_839_000, 3593)
+            // Standard Error: 522_752
+            .saturating_add(Weight::from_parts(71_005_201, 0).saturating_mul(b.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(b.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(b.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(b.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Identity::IdentityOf` (r:1 w:0)
+    /// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`:
+    /// Some(7572), added: 10047, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:1)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn refresh_judgement() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `551`
+        //  Estimated: `11037`
+        // Minimum execution time: 55_478_000 picoseconds.
+        Weight::from_parts(59_588_000, 11037)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `VotingModule::ViewpointChanges` (r:1 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:10 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`) The range of component
+    /// `p` is `[1, 10]`.
+    fn declare_viewpoint(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `570 + p * (32 ±0)`
+        //  Estimated: `3565 + p * (2544 ±0)`
+        // Minimum execution time: 58_281_000 picoseconds.
+        Weight::from_parts(80_211_882, 3565)
+            // Standard Error: 62_483
+            .saturating_add(Weight::from_parts(4_362_281, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 2544).saturating_mul(p.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:10 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ViewpointChanges` (r:0 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`) The range of component
+    /// `p` is `[1, 10]`.
+    fn set_viewpoint(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `512 + p * (32 ±0)`
+        //  Estimated: `3565 + p * (2544 ±0)`
+        // Minimum execution time: 59_159_000 picoseconds.
+        Weight::from_parts(65_283_037, 3565)
+            // Standard Error: 52_283
+            .saturating_add(Weight::from_parts(5_288_606, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 2544).saturating_mul(p.into()))
+    }
+    /// Storage: `VotingModule::NextImportAt` (r:1 w:1)
+    /// Proof: `VotingModule::NextImportAt` (`max_values`: Some(1), `max_size`:
+    /// Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Importers` (r:1 w:0)
+    /// Proof: `VotingModule::Importers` (`max_values`: None, `max_size`:
+    /// Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForCandidates` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForCandidates` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Candidates` (r:32 w:32)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:32 w:0)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`) The range of component
+    /// `n` is `[1, 32]`.
+    fn submit_candidates(n: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `157`
+        //  Estimated: `3497 + n * (2585 ±0)`
+        // Minimum execution time: 57_344_000 picoseconds.
+        Weight::from_parts(31_829_297, 3497)
+            // Standard Error: 77_769
+            .saturating_add(Weight::from_parts(30_004_853, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2585).saturating_mul(n.into()))
+    }
+    /// Storage: `VotingModule::Candidates` (r:1 w:1)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForCandidates` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForCandidates` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn expire_candidate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `256`
+        //  Estimated: `3575`
+        // Minimum execution time: 37_318_000 picoseconds.
+        Weight::from_parts(41_010_000, 3575)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Importers` (r:0 w:1)
+    /// Proof: `VotingModule::Importers` (`max_values`: None, `max_size`:
+    /// Some(32), added: 2507, mode: `MaxEncodedLen`)
+    fn set_importer() -> Weight {
         // Proof Size summary in bytes:
         //  Measured:  `0`
         //  Estimated: `0`
-        // Minimum execution time: 10_000_000 picoseconds.
-        Weight::from_parts(10_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+        // Minimum execution time: 14_878_000 picoseconds.
+        Weight::from_parts(18_991_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
     }
-
-    /// Storage: VotingModule Something (r:1 w:1)
-    /// Proof: VotingModule Something (max_values: Some(1), max_size: Some(4),
-    /// added: 499, mode: MaxEncodedLen)
-    fn cause_error() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `32`
-        //  Estimated: `1489`
-        // Minimum execution time: 6_000_000 picoseconds.
-        Weight::from_parts(6_000_000, 1489)
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Candidates` (r:1 w:1)
+    /// Proof: `VotingModule::Candidates` (`max_values`: None, `max_size`:
+    /// Some(110), added: 2585, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForCandidates` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForCandidates` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn dismiss_candidate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `356`
+        //  Estimated: `3575`
+        // Minimum execution time: 53_324_000 picoseconds.
+        Weight::from_parts(58_886_000, 3575)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Members` (r:2 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:1 w:0)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:4 w:1)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:1 w:1)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`) The range of
+    /// component `c` is `[1, 3]`.
+    fn delegate(c: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `95 + c * (208 ±0)`
+        //  Estimated: `6044 + c * (3036 ±0)`
+        // Minimum execution time: 58_941_000 picoseconds.
+        Weight::from_parts(57_168_494, 6044)
+            // Standard Error: 201_289
+            .saturating_add(Weight::from_parts(12_346_097, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(Weight::from_parts(0, 3036).saturating_mul(c.into()))
+    }
+    /// Storage: `VotingModule::Delegations` (r:1 w:1)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:1 w:1)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    fn undelegate() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `310`
+        //  Estimated: `5610`
+        // Minimum execution time: 43_963_000 picoseconds.
+        Weight::from_parts(49_378_000, 5610)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:0)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn replenish_credits() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `246`
+        //  Estimated: `3517`
+        // Minimum execution time: 24_729_000 picoseconds.
+        Weight::from_parts(27_658_000, 3517).saturating_add(RocksDbWeight::get().reads(1_u64))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`) The range of
+    /// component `p` is `[1, 10]`.
+    fn withdraw_proposal(p: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `677 + p * (124 ±0)`
+        //  Estimated: `6176`
+        // Minimum execution time: 112_905_000 picoseconds.
+        Weight::from_parts(134_267_010, 6176)
+            // Standard Error: 115_012
+            .saturating_add(Weight::from_parts(2_901_568, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:65 w:64)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:64 w:64)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:64 w:64)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:1 w:1)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::NoteTargets` (r:1 w:1)
+    /// Proof: `VotingModule::NoteTargets` (`max_values`: None, `max_size`:
+    /// Some(106), added: 2581, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:1 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::TargetNotes` (r:0 w:1)
+    /// Proof: `VotingModule::TargetNotes` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`) The range of
+    /// component `v` is `[0, 64]`.
+    fn cancel_proposal(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `941 + v * (201 ±0)`
+        //  Estimated: `6176 + v * (2575 ±0)`
+        // Minimum execution time: 158_548_000 picoseconds.
+        Weight::from_parts(181_209_605, 6176)
+            // Standard Error: 158_770
+            .saturating_add(Weight::from_parts(34_571_672, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2575).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::CancelledProposals` (r:1 w:1)
+    /// Proof: `VotingModule::CancelledProposals` (`max_values`: None,
+    /// `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:65 w:64)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:64 w:64)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Members` (r:64 w:64)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:1 w:0)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`) The range of component `v` is
+    /// `[1, 64]`.
+    fn refund_cancelled(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `435 + v * (201 ±0)`
+        //  Estimated: `3534 + v * (2575 ±0)`
+        // Minimum execution time: 104_313_000 picoseconds.
+        Weight::from_parts(62_834_188, 3534)
+            // Standard Error: 512_588
+            .saturating_add(Weight::from_parts(27_793_000, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2575).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Commits` (r:1 w:0)
+    /// Proof: `VotingModule::Commits` (`max_values`: None, `max_size`:
+    /// Some(100), added: 2575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:10 w:10)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:10 w:10)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CounterForMembers` (r:1 w:1)
+    /// Proof: `VotingModule::CounterForMembers` (`max_values`: Some(1),
+    /// `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegations` (r:33 w:33)
+    /// Proof: `VotingModule::Delegations` (`max_values`: None, `max_size`:
+    /// Some(561), added: 3036, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Delegators` (r:9 w:9)
+    /// Proof: `VotingModule::Delegators` (`max_values`: None, `max_size`:
+    /// Some(2145), added: 4620, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ViewpointChanges` (r:0 w:1)
+    /// Proof: `VotingModule::ViewpointChanges` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::CreditEpochs` (r:0 w:1)
+    /// Proof: `VotingModule::CreditEpochs` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Viewpoints` (r:0 w:1)
+    /// Proof: `VotingModule::Viewpoints` (`max_values`: None, `max_size`:
+    /// Some(33), added: 2508, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Suspended` (r:0 w:1)
+    /// Proof: `VotingModule::Suspended` (`max_values`: None, `max_size`:
+    /// Some(36), added: 2511, mode: `MaxEncodedLen`) The range of component
+    /// `p` is `[0, 10]`. The range of component `d` is `[0, 40]`.
+    fn force_remove_member(p: u32, d: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1029 + d * (125 ±0) + p * (272 ±0)`
+        //  Estimated: `23575 + d * (2552 ±17) + p * (5186 ±66)`
+        // Minimum execution time: 402_895_000 picoseconds.
+        Weight::from_parts(153_314_932, 23575)
+            // Standard Error: 274_703
+            .saturating_add(Weight::from_parts(29_204_690, 0).saturating_mul(p.into()))
+            // Standard Error: 71_212
+            .saturating_add(Weight::from_parts(17_459_400, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+            .saturating_add(RocksDbWeight::get().writes(8_u64))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(p.into())))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(d.into())))
+            .saturating_add(Weight::from_parts(0, 2552).saturating_mul(d.into()))
+            .saturating_add(Weight::from_parts(0, 5186).saturating_mul(p.into()))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:2 w:2)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    fn force_close_vote() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `471`
+        //  Estimated: `7286`
+        // Minimum execution time: 91_383_000 picoseconds.
+        Weight::from_parts(99_824_000, 7286)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `VotingModule::ProposalData` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Deadlines` (r:2 w:2)
+    /// Proof: `VotingModule::Deadlines` (`max_values`: None, `max_size`:
+    /// Some(673), added: 3148, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Votes` (r:257 w:256)
+    /// Proof: `VotingModule::Votes` (`max_values`: None, `max_size`: Some(69),
+    /// added: 2544, mode: `MaxEncodedLen`) Storage: `VotingModule::Members`
+    /// (r:256 w:256) Proof: `VotingModule::Members` (`max_values`: None,
+    /// `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Reputations` (r:256 w:256)
+    /// Proof: `VotingModule::Reputations` (`max_values`: None, `max_size`:
+    /// Some(80), added: 2555, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:258 w:258)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::PotInflows` (r:1 w:1)
+    /// Proof: `VotingModule::PotInflows` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Pot` (r:1 w:1)
+    /// Proof: `VotingModule::Pot` (`max_values`: Some(1), `max_size`: Some(80),
+    /// added: 575, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:1 w:1)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:1)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Committers` (r:1 w:0)
+    /// Proof: `VotingModule::Committers` (`max_values`: None, `max_size`:
+    /// Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalCalls` (r:1 w:0)
+    /// Proof: `VotingModule::ProposalCalls` (`max_values`: None, `max_size`:
+    /// Some(16420), added: 18895, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalResults` (r:0 w:1)
+    /// Proof: `VotingModule::ProposalResults` (`max_values`: None, `max_size`:
+    /// Some(128), added: 2603, mode: `MaxEncodedLen`) The range of
+    /// component `v` is `[1, 256]`.
+    fn force_close_reveal(v: u32) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `640 + v * (232 ±0)`
+        //  Estimated: `19885 + v * (2605 ±0)`
+        // Minimum execution time: 260_292_000 picoseconds.
+        Weight::from_parts(97_883_336, 19885)
+            // Standard Error: 1_678_450
+            .saturating_add(Weight::from_parts(98_222_304, 0).saturating_mul(v.into()))
+            .saturating_add(RocksDbWeight::get().reads(13_u64))
+            .saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(v.into())))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(v.into())))
+            .saturating_add(Weight::from_parts(0, 2605).saturating_mul(v.into()))
+    }
+    /// Storage: `VotingModule::Members` (r:1 w:1)
+    /// Proof: `VotingModule::Members` (`max_values`: None, `max_size`:
+    /// Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Parameters` (r:1 w:0)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128),
+    /// added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::Proposals` (r:1 w:0)
+    /// Proof: `VotingModule::Proposals` (`max_values`: Some(1), `max_size`:
+    /// Some(321), added: 816, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalData` (r:10 w:0)
+    /// Proof: `VotingModule::ProposalData` (`max_values`: None, `max_size`:
+    /// Some(2711), added: 5186, mode: `MaxEncodedLen`)
+    /// Storage: `VotingModule::ProposalBonds` (r:10 w:0)
+    /// Proof: `VotingModule::ProposalBonds` (`max_values`: None, `max_size`:
+    /// Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn force_set_member_balance() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `2958`
+        //  Estimated: `52850`
+        // Minimum execution time: 250_075_000 picoseconds.
+        Weight::from_parts(264_757_000, 52850)
+            .saturating_add(RocksDbWeight::get().reads(24_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `VotingModule::Parameters` (r:1 w:1)
+    /// Proof: `VotingModule::Parameters` (`max_values`: Some(1), `max_size`:
+    /// Some(37), added: 532, mode: `MaxEncodedLen`)
+    fn set_parameter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `119`
+        //  Estimated: `1522`
+        // Minimum execution time: 18_645_000 picoseconds.
+        Weight::from_parts(19_488_000, 1522)
             .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
@@ -23,7 +23,6 @@
 //
 // For more information, please refer to <http://unlicense.org>
 
-use alloc::vec;
 use alloc::vec::Vec;
 
 use frame_support::genesis_builder_helper::build_state;
@@ -43,8 +42,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, Hash, InherentDataExt, Nonce,
+    Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
+    VoteCredit, VotingModule, VERSION,
 };
 
 impl_runtime_apis! {
@@ -171,6 +171,44 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_voting_runtime_api::VotingApi<Block, AccountId, Balance, BlockNumber, Hash, VoteCredit> for Runtime {
+        fn active_proposals() -> Vec<pallet_voting_runtime_api::ProposalInfo<AccountId, BlockNumber, Hash>> {
+            VotingModule::active_proposals()
+        }
+
+        fn proposal(proposal_hash: Hash) -> Option<pallet_voting_runtime_api::ProposalInfo<AccountId, BlockNumber, Hash>> {
+            VotingModule::proposal_info(proposal_hash)
+        }
+
+        fn proposal_result(proposal_hash: Hash) -> Option<pallet_voting_runtime_api::ProposalResult<BlockNumber, Balance>> {
+            pallet_voting::ProposalResults::<Runtime>::get(proposal_hash)
+        }
+
+        fn member(account: AccountId) -> Option<pallet_voting_runtime_api::VoterBalance<Balance, VoteCredit>> {
+            VotingModule::member(&account)
+        }
+
+        fn pending_commits(account: AccountId) -> Vec<(Hash, VoteCredit)> {
+            VotingModule::pending_commits(&account)
+        }
+
+        fn estimate_payout(account: AccountId, proposal_hash: Hash) -> Option<pallet_voting_runtime_api::PayoutEstimate<Balance>> {
+            VotingModule::estimate_payout(&account, proposal_hash)
+        }
+
+        fn reputation(account: AccountId) -> pallet_voting_runtime_api::Reputation<Balance> {
+            VotingModule::reputation(&account)
+        }
+
+        fn notes_for_target(target: pallet_voting_runtime_api::NoteTarget<Hash>) -> Vec<Hash> {
+            VotingModule::notes_for_target(&target)
+        }
+
+        fn candidates() -> Vec<(Hash, pallet_voting_runtime_api::Candidate<Hash, BlockNumber>)> {
+            VotingModule::candidates()
+        }
+    }
+
     impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
         fn query_info(
             uxt: <Block as BlockT>::Extrinsic,
@@ -244,7 +282,9 @@ impl_runtime_apis! {
             use baseline::Pallet as BaselineBench;
             use super::*;
 
+            #[allow(non_local_definitions)]
             impl frame_system_benchmarking::Config for Runtime {}
+            #[allow(non_local_definitions)]
             impl baseline::Config for Runtime {}
 
             use frame_support::traits::WhitelistedStorageKeys;
@@ -286,11 +326,11 @@ impl_runtime_apis! {
         }
 
         fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<Vec<u8>> {
-            get_preset::<RuntimeGenesisConfig>(id, |_| None)
+            get_preset::<RuntimeGenesisConfig>(id, crate::genesis_config_presets::get_preset)
         }
 
         fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
-            vec![]
+            crate::genesis_config_presets::preset_names()
         }
     }
 }
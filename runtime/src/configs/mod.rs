@@ -25,6 +25,7 @@
 
 // Substrate and Polkadot dependencies
 use frame_support::derive_impl;
+use frame_support::dispatch::DispatchResult;
 use frame_support::parameter_types;
 use frame_support::traits::ConstBool;
 use frame_support::traits::ConstU128;
@@ -42,9 +43,13 @@ use frame_system::limits::BlockWeights;
 use pallet_transaction_payment::ConstFeeMultiplier;
 use pallet_transaction_payment::FungibleAdapter;
 use pallet_transaction_payment::Multiplier;
+use pallet_voting::types::CreditPolicy;
+use pallet_voting::types::IdentityJudgement;
+use pallet_voting::types::TokenPolicy;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::traits::One;
-use sp_runtime::MultiSignature;
+use sp_runtime::transaction_validity::TransactionPriority;
 use sp_runtime::Perbill;
 use sp_version::RuntimeVersion;
 
@@ -65,11 +70,14 @@ use super::RuntimeHoldReason;
 use super::RuntimeOrigin;
 use super::RuntimeTask;
 use super::System;
+use super::UncheckedExtrinsic;
+use super::VoteCredit;
 use super::EXISTENTIAL_DEPOSIT;
 use super::SLOT_DURATION;
 use super::VERSION;
 use crate::Signature;
 use crate::DAYS;
+use crate::MINUTES;
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
 
@@ -239,9 +247,89 @@ impl pallet_identity::Config for Runtime {
 
 pub struct VotingIdentityProvider;
 
+impl VotingIdentityProvider {
+    /// The best judgement of the identity set by `account` itself
+    fn own_judgement(account: &AccountId) -> Option<IdentityJudgement> {
+        let (registration, _) = crate::Identity::identity(account.clone())?;
+        let judgement = registration
+            .judgements
+            .iter()
+            .map(|(_, judgement)| match judgement {
+                pallet_identity::Judgement::KnownGood => IdentityJudgement::KnownGood,
+                pallet_identity::Judgement::Reasonable => IdentityJudgement::Reasonable,
+                _ => IdentityJudgement::Unknown,
+            })
+            .max()
+            .unwrap_or(IdentityJudgement::Unknown);
+        Some(judgement)
+    }
+}
+
 impl pallet_voting::IdentityProvider<AccountId> for VotingIdentityProvider {
-    fn check_existence(account: &AccountId) -> bool {
-        crate::Identity::identity(account.clone()).is_some()
+    fn check_existence(account: &AccountId) -> bool { Self::judgement(account).is_some() }
+
+    fn judgement(account: &AccountId) -> Option<IdentityJudgement> { Self::own_judgement(account) }
+
+    fn super_of(account: &AccountId) -> Option<AccountId> {
+        crate::Identity::super_of(account.clone()).map(|(parent, _)| parent)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_identity(account: &AccountId, display: &[u8]) -> DispatchResult {
+        <Self as pallet_voting::IdentitySeeder<AccountId>>::seed_identity(account, display)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_judgement(registrar: &AccountId, account: &AccountId) -> DispatchResult {
+        <Self as pallet_voting::IdentitySeeder<AccountId>>::seed_judgement(registrar, account)
+    }
+}
+
+impl pallet_voting::IdentitySeeder<AccountId> for VotingIdentityProvider {
+    fn seed_identity(account: &AccountId, display: &[u8]) -> DispatchResult {
+        let display = display[..display.len().min(32)].to_vec();
+        let info = pallet_identity::legacy::IdentityInfo::<MaxAdditionalFields> {
+            display: pallet_identity::Data::Raw(display.try_into().unwrap_or_default()),
+            additional: Default::default(),
+            legal: Default::default(),
+            web: Default::default(),
+            riot: Default::default(),
+            twitter: Default::default(),
+            email: Default::default(),
+            pgp_fingerprint: Default::default(),
+            image: Default::default(),
+        };
+        crate::Identity::set_identity(
+            RuntimeOrigin::signed(account.clone()),
+            alloc::boxed::Box::new(info),
+        )
+        .map(|_| ())
+        .map_err(|e| e.error)
+    }
+
+    fn seed_judgement(registrar: &AccountId, account: &AccountId) -> DispatchResult {
+        let (registration, _) = crate::Identity::identity(account.clone())
+            .ok_or(pallet_identity::Error::<Runtime>::NoIdentity)?;
+        let index = match crate::Identity::registrars()
+            .iter()
+            .position(|r| r.as_ref().is_some_and(|r| &r.account == registrar))
+        {
+            Some(index) => index,
+            None => {
+                crate::Identity::add_registrar(RuntimeOrigin::root(), registrar.clone().into())
+                    .map_err(|e| e.error)?;
+                crate::Identity::registrars().len() - 1
+            }
+        };
+        crate::Identity::provide_judgement(
+            RuntimeOrigin::signed(registrar.clone()),
+            index as u32,
+            account.clone().into(),
+            pallet_identity::Judgement::Reasonable,
+            <BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&registration.info),
+        )
+        .map(|_| ())
+        .map_err(|e| e.error)
     }
 }
 
@@ -250,24 +338,97 @@ pub const UNIT: u128 = 1000000000000;
 parameter_types! {
     pub const EntryFee: Balance = 30_000 * UNIT;
     pub const MaxProposals: u32 = 10u32;
+    pub const ProposalBond: Balance = 1_000 * UNIT;
+    pub const BondSlash: Perbill = Perbill::from_percent(50);
     pub const RevealLength: BlockNumber = 7u32;
     pub const MinLength: BlockNumber = 15u32;
-    pub const MaxTokens: u8 = 100u8;
+    pub const ResultRetention: BlockNumber = 30 * DAYS;
+    pub const NonRevealPenalty: Perbill = Perbill::from_percent(10);
+    pub const SideSlash: Perbill = Perbill::from_percent(10);
+    pub const UnrevealedTokens: TokenPolicy = TokenPolicy::Release;
+    pub const Credits: CreditPolicy<BlockNumber, VoteCredit> = CreditPolicy::RefundOnClose;
+    pub const MinJudgement: IdentityJudgement = IdentityJudgement::Reasonable;
+    pub const ReapLimit: u32 = 64;
+    pub const MaxClosesPerBlock: u32 = 2;
+    pub const MaxBeneficiaries: u32 = 64;
+    pub const MaxVotersPerProposal: u32 = 256;
+    pub const MaxTokens: u32 = 100;
     pub const VotingPalletId: PalletId = PalletId(*b"p/v8t1ng");
+    pub const MaxCallLen: u32 = 16 * 1024;
+    pub MaxCallWeight: Weight = Perbill::from_percent(25) * RuntimeBlockWeights::get().max_block;
+    pub const ImportInterval: BlockNumber = 10 * MINUTES;
+    pub const MaxImportBatch: u32 = 32;
+    pub const MaxCandidates: u32 = 512;
+    pub const ImportPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    pub const MaxDelegators: u32 = 32;
+    pub const MaxDelegationDepth: u32 = 3;
+    pub const ViewpointCooldown: BlockNumber = 7 * DAYS;
+    pub const CandidateLifetime: BlockNumber = 7 * DAYS;
+}
+
+/// Lets the offchain worker of the voting pallet sign the posts it imports.
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
+    type Signature = Signature;
 }
 
+/// Lets the offchain worker of the voting pallet submit the posts it imports
+/// as unsigned transactions.
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type Extrinsic = UncheckedExtrinsic;
+    type OverarchingCall = RuntimeCall;
+}
+
+/// Origin of the calls dispatched by approved community notes. Other pallets
+/// can use it to let the voting committee act on their behalf.
+pub type EnsureVotingApproved = pallet_voting::EnsureVotingApproved<Hash>;
+
 /// Configure the pallet-voting in pallets/voting.
 impl pallet_voting::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_voting::weights::SubstrateWeight<Runtime>;
     type IdentityProvider = VotingIdentityProvider;
+    type IdentitySeeder = VotingIdentityProvider;
     type Currency = Balances;
     type BasicDeposit = EntryFee;
     type MaxProposals = MaxProposals;
-    type Public = <Signature as sp_runtime::traits::Verify>::Signer;
-    type Signature = MultiSignature;
+    type ProposalBond = ProposalBond;
+    type BondSlash = BondSlash;
+    type ResultRetention = ResultRetention;
+    type NonRevealPenalty = NonRevealPenalty;
+    type SlashPolicy = pallet_voting::slashing::Percentage<SideSlash>;
+    type OutcomeRule = pallet_voting::outcome::Majority;
+    type MinJudgement = MinJudgement;
+    type PotOrigin = EnsureRootOrHalfCouncil;
+    type ViewpointOrigin = EnsureRootOrHalfCouncil;
+    type ViewpointCooldown = ViewpointCooldown;
+    type CancelOrigin = EnsureRootOrHalfCouncil;
+    type AdminOrigin = EnsureRootOrHalfCouncil;
+    type ParameterOrigin = EnsureRootOrHalfCouncil;
+    type UnrevealedTokens = UnrevealedTokens;
+    type CreditPolicy = Credits;
+    type ReapLimit = ReapLimit;
+    type MaxClosesPerBlock = MaxClosesPerBlock;
+    type MaxBeneficiaries = MaxBeneficiaries;
+    type MaxVotersPerProposal = MaxVotersPerProposal;
     type RevealLength = RevealLength;
     type MinLength = MinLength;
+    type VoteCredit = VoteCredit;
     type MaxVotingTokens = MaxTokens;
     type PalletId = VotingPalletId;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type MaxCallLen = MaxCallLen;
+    type MaxCallWeight = MaxCallWeight;
+    type ImportInterval = ImportInterval;
+    type MaxImportBatch = MaxImportBatch;
+    type MaxCandidates = MaxCandidates;
+    type UnsignedPriority = ImportPriority;
+    type AuthorityId = pallet_voting::offchain::crypto::ImporterId;
+    type CandidateLifetime = CandidateLifetime;
+    type MaxDelegators = MaxDelegators;
+    type MaxDelegationDepth = MaxDelegationDepth;
 }
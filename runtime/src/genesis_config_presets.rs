@@ -0,0 +1,161 @@
+//! Named genesis presets of the runtime, served through
+//! `sp_genesis_builder::GenesisBuilder` so that chain specs can be built from
+//! them by name.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use serde_json::json;
+use serde_json::Value;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_genesis_builder::PresetId;
+use sp_genesis_builder::DEV_RUNTIME_PRESET;
+use sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET;
+use sp_keyring::Ed25519Keyring;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::traits::BlakeTwo256;
+
+use crate::configs::EntryFee;
+use crate::configs::MaxTokens;
+use crate::configs::MinLength;
+use crate::AccountId;
+use crate::Hash;
+
+/// A local testnet whose voting committee already has a proposal in each
+/// phase.
+pub const VOTING_DEMO_PRESET: &str = "voting-demo";
+
+/// Generate an Aura and Grandpa authority key pair.
+fn authority_keys(aura: Sr25519Keyring, grandpa: Ed25519Keyring) -> (AuraId, GrandpaId) {
+    (aura.public().into(), grandpa.public().into())
+}
+
+/// Configure initial storage state for FRAME modules.
+fn testnet_genesis(
+    initial_authorities: Vec<(AuraId, GrandpaId)>,
+    root_key: Sr25519Keyring,
+    endowed_accounts: Vec<AccountId>,
+    committee: &[Sr25519Keyring],
+) -> Value {
+    json!({
+        "balances": {
+            // Configure endowed accounts with initial balance of 1 << 60.
+            "balances": endowed_accounts.iter().cloned().map(|k| (k, 1u64 << 60)).collect::<Vec<_>>(),
+        },
+        "aura": {
+            "authorities": initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
+        },
+        "grandpa": {
+            "authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
+        },
+        "sudo": {
+            // Assign network admin rights.
+            "key": Some(root_key.to_account_id()),
+        },
+        "votingModule": {
+            // Give the committee an identity judged by the root key and make
+            // them members with full credits.
+            "identities": committee
+                .iter()
+                .map(|k| (k.to_account_id(), <&str>::from(*k).as_bytes()))
+                .collect::<Vec<_>>(),
+            "registrar": Some(root_key.to_account_id()),
+            "members": committee
+                .iter()
+                .map(|k| (k.to_account_id(), MaxTokens::get(), EntryFee::get()))
+                .collect::<Vec<_>>(),
+            // The root key signs the imports of the offchain worker.
+            "importers": vec![root_key.to_account_id()],
+        },
+    })
+}
+
+/// Return the development genesis config.
+pub fn development_config_genesis() -> Value {
+    testnet_genesis(
+        vec![authority_keys(Sr25519Keyring::Alice, Ed25519Keyring::Alice)],
+        Sr25519Keyring::Alice,
+        vec![
+            Sr25519Keyring::Alice.to_account_id(),
+            Sr25519Keyring::Bob.to_account_id(),
+            Sr25519Keyring::AliceStash.to_account_id(),
+            Sr25519Keyring::BobStash.to_account_id(),
+        ],
+        &[Sr25519Keyring::Alice, Sr25519Keyring::Bob],
+    )
+}
+
+/// Return the local testnet genesis config.
+pub fn local_config_genesis() -> Value {
+    testnet_genesis(
+        vec![
+            authority_keys(Sr25519Keyring::Alice, Ed25519Keyring::Alice),
+            authority_keys(Sr25519Keyring::Bob, Ed25519Keyring::Bob),
+        ],
+        Sr25519Keyring::Alice,
+        Sr25519Keyring::iter()
+            .filter(|k| !matches!(k, Sr25519Keyring::One | Sr25519Keyring::Two))
+            .map(|k| k.to_account_id())
+            .collect(),
+        &[
+            Sr25519Keyring::Alice,
+            Sr25519Keyring::Bob,
+            Sr25519Keyring::Charlie,
+            Sr25519Keyring::Dave,
+            Sr25519Keyring::Eve,
+            Sr25519Keyring::Ferdie,
+        ],
+    )
+}
+
+/// Return the voting demo genesis config: the local testnet with one proposal
+/// open for commits and one open for reveals.
+pub fn voting_demo_config_genesis() -> Value {
+    let mut genesis = local_config_genesis();
+    genesis["votingModule"]["proposals"] = json!([(
+        Sr25519Keyring::Bob.to_account_id(),
+        b"Demo note open for commits".to_vec(),
+        demo_target(b"https://example.com/posts/1"),
+        MinLength::get() * 4,
+    )]);
+    genesis["votingModule"]["revealing"] = json!([(
+        Sr25519Keyring::Charlie.to_account_id(),
+        b"Demo note open for reveals".to_vec(),
+        demo_target(b"https://example.com/posts/2"),
+    )]);
+    genesis
+}
+
+/// The platform and content hash of a demo post at `url`.
+fn demo_target(url: &[u8]) -> (Vec<u8>, Hash) {
+    (
+        b"example.com".to_vec(),
+        <BlakeTwo256 as sp_runtime::traits::Hash>::hash(url),
+    )
+}
+
+/// Provides the JSON representation of predefined genesis config for given
+/// `id`.
+pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+    let patch = match core::str::from_utf8(id.as_ref()) {
+        Ok(DEV_RUNTIME_PRESET) => development_config_genesis(),
+        Ok(LOCAL_TESTNET_RUNTIME_PRESET) => local_config_genesis(),
+        Ok(VOTING_DEMO_PRESET) => voting_demo_config_genesis(),
+        _ => return None,
+    };
+    Some(
+        serde_json::to_string(&patch)
+            .expect("serialization to json is expected to work. qed.")
+            .into_bytes(),
+    )
+}
+
+/// List of supported presets.
+pub fn preset_names() -> Vec<PresetId> {
+    vec![
+        PresetId::from(DEV_RUNTIME_PRESET),
+        PresetId::from(LOCAL_TESTNET_RUNTIME_PRESET),
+        PresetId::from(VOTING_DEMO_PRESET),
+    ]
+}
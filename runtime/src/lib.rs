@@ -7,6 +7,7 @@ pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 pub mod configs;
+pub mod genesis_config_presets;
 
 extern crate alloc;
 use alloc::vec::Vec;
@@ -137,6 +138,9 @@ pub type Nonce = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Voting credits of the members of the voting committee.
+pub type VoteCredit = u32;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -179,7 +183,15 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_voting::migrations::v1::MigrateToV1<Runtime, Signature>,
+    pallet_voting::migrations::v2::MigrateToV2<Runtime>,
+    pallet_voting::migrations::v3::MigrateToV3<Runtime>,
+    pallet_voting::migrations::v4::MigrateToV4<Runtime>,
+    pallet_voting::migrations::v5::MigrateToV5<Runtime>,
+    pallet_voting::migrations::v6::MigrateToV6<Runtime>,
+    pallet_voting::migrations::v7::MigrateToV7<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<